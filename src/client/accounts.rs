@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use crate::models::account::AccountConfig;
+use crate::models::client::Client;
+use crate::models::error::Error;
+
+impl Client {
+    /// Register a named account with its own credentials.
+    ///
+    /// The account is a [`Client`] sharing this client's HTTP connection pool, API URLs,
+    /// retry configuration, download archive, sidecar, playlist file and lenient parsing
+    /// settings, so every method can be called on it. Adding an account under an existing
+    /// name replaces it.
+    pub async fn add_account(
+        &self,
+        name: impl Into<String>,
+        config: AccountConfig,
+    ) -> Result<Arc<Client>, Error> {
+        let client_id = match config.client_id {
+            Some(client_id) => client_id,
+            None => self.get_client_id_value().await,
+        };
+        let mut account = Client::with_client_id(client_id, self.retry_config.clone());
+        account.http = self.http.clone();
+        account.api_url = self.api_url.clone();
+        account.public_api_url = self.public_api_url.clone();
+        account.archive_config = self.archive_config.clone();
+        account.download_archive = self.download_archive.clone();
+        account.sidecar_format = self.sidecar_format;
+        account.playlist_formats = self.playlist_formats.clone();
+        account.lenient = self.lenient;
+        account.oauth_config = config.oauth_config;
+        account.token_store = config.token_store;
+        account.init_token(config.token).await?;
+
+        let account = Arc::new(account);
+        self.accounts
+            .write()
+            .expect("Account registry poisoned")
+            .insert(name.into(), account.clone());
+        Ok(account)
+    }
+
+    /// The account registered under `name`.
+    pub fn account(&self, name: &str) -> Result<Arc<Client>, Error> {
+        self.accounts
+            .read()
+            .expect("Account registry poisoned")
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::new(format!("No account named {name}")))
+    }
+
+    /// Names of all registered accounts, sorted.
+    pub fn account_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .accounts
+            .read()
+            .expect("Account registry poisoned")
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+
+    pub fn remove_account(&self, name: &str) -> Option<Arc<Client>> {
+        self.accounts
+            .write()
+            .expect("Account registry poisoned")
+            .remove(name)
+    }
+}
@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::models::auth::{OAuthConfig, OAuthFlow, PkceChallenge, Token, random_string};
+use crate::models::client::Client;
+use crate::models::error::Error;
+use crate::models::token_store::{TokenStore, TokenStoreLock};
+
+const CALLBACK_RESPONSE: &str =
+    "<html><body>Authorization complete. You can close this window.</body></html>";
+
+impl Client {
+    /// Run the authorization code + PKCE flow.
+    ///
+    /// Listens on the loopback `redirect_uri` from the OAuth configuration, calls `open_url`
+    /// with the authorization URL (e.g. to open a browser) and waits for the redirect. The
+    /// resulting token is stored on the client and used for all further requests.
+    pub async fn authorize_with_pkce<F: FnOnce(&str)>(&self, open_url: F) -> Result<Token, Error> {
+        let config = self.oauth_config()?;
+        let redirect_uri = config
+            .redirect_uri
+            .as_deref()
+            .ok_or_else(|| Error::new("Missing OAuth redirect_uri"))?;
+        let redirect = reqwest::Url::parse(redirect_uri)
+            .map_err(|e| Error::from_error("Invalid OAuth redirect_uri", e))?;
+        let host = match redirect.host_str() {
+            Some("localhost") | Some("127.0.0.1") => "127.0.0.1",
+            Some("[::1]") => "::1",
+            _ => return Err(Error::new("OAuth redirect_uri must be a loopback address")),
+        };
+        let port = redirect
+            .port_or_known_default()
+            .ok_or_else(|| Error::new("OAuth redirect_uri is missing a port"))?;
+        let listener = TcpListener::bind((host, port)).await?;
+
+        let pkce = PkceChallenge::new();
+        let state = random_string(32);
+        open_url(&config.authorization_url(&pkce, &state)?);
+
+        let code = loop {
+            let (mut stream, _) = listener.accept().await?;
+            let Some(params) = read_callback(&mut stream, redirect.path()).await? else {
+                continue;
+            };
+            let param = |name: &str| {
+                params
+                    .iter()
+                    .find(|(k, _)| k == name)
+                    .map(|(_, v)| v.clone())
+            };
+            if let Some(error) = param("error") {
+                return Err(Error::new(format!("OAuth authorization failed: {error}")));
+            }
+            if param("state").as_deref() != Some(state.as_str()) {
+                return Err(Error::new("OAuth state mismatch"));
+            }
+            match param("code") {
+                Some(code) => break code,
+                None => return Err(Error::new("OAuth redirect is missing the code")),
+            }
+        };
+
+        let token = config.exchange_code(&code, &pkce.verifier).await?;
+        self.set_token(Some(token.clone())).await?;
+        Ok(token)
+    }
+
+    /// Replace the stored OAuth token, e.g. with one obtained elsewhere.
+    /// The token is also written to the token store, if one is configured, while holding
+    /// the store lock so it cannot interleave with a refresh in another process.
+    pub async fn set_token(&self, token: Option<Token>) -> Result<(), Error> {
+        let mut current = self.token.lock().await;
+        if let Some(store) = self.token_store.clone() {
+            let _lock = lock_store(store.clone()).await?;
+            match token.as_ref() {
+                Some(token) => store.save(token)?,
+                None => store.clear()?,
+            }
+        }
+        *current = token;
+        *self.me_id.lock().await = None;
+        Ok(())
+    }
+
+    /// Set up the token when the client is created: an explicit token is stored,
+    /// otherwise the token store is read.
+    pub(crate) async fn init_token(&self, token: Option<Token>) -> Result<(), Error> {
+        match (token, self.token_store.as_ref()) {
+            (Some(token), _) => self.set_token(Some(token)).await?,
+            (None, Some(store)) => *self.token.lock().await = store.load()?,
+            (None, None) => {}
+        }
+        Ok(())
+    }
+
+    pub async fn get_token(&self) -> Option<Token> {
+        self.token.lock().await.clone()
+    }
+
+    /// Current access token, refreshed first if it is about to expire.
+    /// Returns `None` when the client has no OAuth configuration.
+    pub(crate) async fn access_token(&self) -> Result<Option<String>, Error> {
+        let Some(config) = self.oauth_config.as_ref() else {
+            return Ok(None);
+        };
+        let mut token = self.token.lock().await;
+        let needs_token = match token.as_ref() {
+            Some(t) => t.is_expiring(),
+            None => config.flow == OAuthFlow::ClientCredentials,
+        };
+        if needs_token {
+            self.renew_token(config, &mut token).await?;
+        }
+        Ok(token.as_ref().map(|t| t.access_token.clone()))
+    }
+
+    /// Force a token refresh after the API rejected the current one.
+    /// Returns whether a new token could be obtained.
+    pub(crate) async fn refresh_access_token(&self) -> Result<bool, Error> {
+        let Some(config) = self.oauth_config.as_ref() else {
+            return Ok(false);
+        };
+        let mut token = self.token.lock().await;
+        let can_refresh = token.as_ref().is_some_and(|t| t.refresh_token.is_some())
+            || config.flow == OAuthFlow::ClientCredentials;
+        if !can_refresh {
+            return Ok(false);
+        }
+        self.renew_token(config, &mut token).await?;
+        Ok(true)
+    }
+
+    /// Obtain a new token while holding the token store lock. If another process sharing
+    /// the store refreshed in the meantime, its token is used instead of refreshing again.
+    async fn renew_token(
+        &self,
+        config: &OAuthConfig,
+        token: &mut Option<Token>,
+    ) -> Result<(), Error> {
+        let Some(store) = self.token_store.clone() else {
+            *token = Some(fetch_token(config, token.as_ref()).await?);
+            return Ok(());
+        };
+
+        let _lock = lock_store(store.clone()).await?;
+
+        if let Some(stored) = store.load()?
+            && !stored.is_expiring()
+            && token
+                .as_ref()
+                .is_none_or(|t| t.access_token != stored.access_token)
+        {
+            *token = Some(stored);
+            return Ok(());
+        }
+
+        // The stored refresh token is the newest one if refresh tokens are rotated
+        let current = store.load()?.or_else(|| token.clone());
+        let new_token = fetch_token(config, current.as_ref()).await?;
+        store.save(&new_token)?;
+        *token = Some(new_token);
+        Ok(())
+    }
+
+    fn oauth_config(&self) -> Result<&OAuthConfig, Error> {
+        self.oauth_config
+            .as_ref()
+            .ok_or_else(|| Error::new("Client has no OAuth configuration"))
+    }
+}
+
+/// Take the token store lock without blocking the runtime.
+async fn lock_store(store: Arc<dyn TokenStore>) -> Result<TokenStoreLock, Error> {
+    tokio::task::spawn_blocking(move || store.lock())
+        .await
+        .map_err(|e| Error::from_error("Token store lock task failed", e))?
+}
+
+async fn fetch_token(config: &OAuthConfig, current: Option<&Token>) -> Result<Token, Error> {
+    if let Some(refresh_token) = current.and_then(|t| t.refresh_token.as_deref()) {
+        let mut token = config.refresh(refresh_token).await?;
+        // Keep the old refresh token if the server did not rotate it
+        if token.refresh_token.is_none() {
+            token.refresh_token = Some(refresh_token.to_string());
+        }
+        return Ok(token);
+    }
+    match config.flow {
+        OAuthFlow::ClientCredentials => config.request_client_credentials().await,
+        OAuthFlow::AuthorizationCode => Err(Error::new(
+            "OAuth token expired and cannot be refreshed, authorize again",
+        )),
+    }
+}
+
+/// Read one HTTP request from the redirect listener. Returns the query parameters if it
+/// was a request for the callback path, answering anything else with a 404.
+async fn read_callback(
+    stream: &mut TcpStream,
+    callback_path: &str,
+) -> Result<Option<Vec<(String, String)>>, Error> {
+    let mut buf = vec![0u8; 8192];
+    let mut len = 0;
+    while len < buf.len() {
+        let n = stream.read(&mut buf[len..]).await?;
+        if n == 0 {
+            break;
+        }
+        len += n;
+        if buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+            break;
+        }
+    }
+    let request = String::from_utf8_lossy(&buf[..len]);
+    let target = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+    let url = reqwest::Url::parse(&format!("http://localhost{target}"))
+        .map_err(|e| Error::from_error("Invalid OAuth redirect request", e))?;
+
+    if url.path() != callback_path {
+        stream
+            .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .await?;
+        return Ok(None);
+    }
+
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        CALLBACK_RESPONSE.len(),
+        CALLBACK_RESPONSE
+    );
+    stream.write_all(response.as_bytes()).await?;
+    Ok(Some(url.query_pairs().into_owned().collect()))
+}
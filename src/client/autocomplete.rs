@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+use crate::models::client::Client;
+use crate::models::error::Error;
+use crate::models::query::AutocompleteQuery;
+use crate::models::response::Autocomplete;
+
+/// Debounced autocomplete for interactive search boxes.
+///
+/// Call [`Autocompleter::suggest`] on every keystroke. Each call waits for the debounce
+/// delay first and is cancelled as soon as a newer call starts, including while its
+/// request is in flight, so only the latest input reaches the API.
+#[derive(Debug, Clone)]
+pub struct Autocompleter {
+    client: Arc<Client>,
+    debounce: Duration,
+    query: AutocompleteQuery,
+    latest: Arc<watch::Sender<u64>>,
+}
+
+impl Autocompleter {
+    pub fn new(client: Arc<Client>, debounce: Duration) -> Self {
+        Self {
+            client,
+            debounce,
+            query: AutocompleteQuery::default(),
+            latest: Arc::new(watch::Sender::new(0)),
+        }
+    }
+
+    /// Limits and paging sent with every request, `q` is replaced by the input.
+    pub fn with_query(mut self, query: AutocompleteQuery) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// Suggestions for `input`, or `None` if a newer call superseded this one.
+    pub async fn suggest(&self, input: &str) -> Result<Option<Autocomplete>, Error> {
+        self.latest.send_modify(|generation| *generation += 1);
+        let mut newer = self.latest.subscribe();
+
+        let query = AutocompleteQuery {
+            q: Some(input.to_string()),
+            ..self.query.clone()
+        };
+        let request = async {
+            tokio::time::sleep(self.debounce).await;
+            self.client.autocomplete(&query).await
+        };
+        tokio::select! {
+            result = request => result.map(Some),
+            _ = newer.changed() => Ok(None),
+        }
+    }
+
+    /// Cancel the pending call, if any.
+    pub fn cancel(&self) {
+        self.latest.send_modify(|generation| *generation += 1);
+    }
+}
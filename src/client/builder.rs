@@ -1,10 +1,32 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::models::account::AccountConfig;
+use crate::models::archive::DownloadArchive;
+use crate::models::auth::{OAuthConfig, Token};
 use crate::models::client::Client;
-use crate::models::config::RetryConfig;
+use crate::models::config::{ArchiveConfig, RetryConfig};
 use crate::models::error::Error;
+use crate::models::playlist_file::PlaylistFormat;
+use crate::models::sidecar::SidecarFormat;
+use crate::models::token_store::TokenStore;
 
 #[derive(Debug)]
 pub struct ClientBuilder {
     retry_config: RetryConfig,
+    client_id: Option<String>,
+    api_url: Option<String>,
+    public_api_url: Option<String>,
+    oauth_config: Option<OAuthConfig>,
+    token: Option<Token>,
+    token_store: Option<Arc<dyn TokenStore>>,
+    archive_path: Option<PathBuf>,
+    redownload_on_upgrade: bool,
+    sidecar_format: Option<SidecarFormat>,
+    playlist_formats: Vec<PlaylistFormat>,
+    accounts: Vec<(String, AccountConfig)>,
+    lenient: bool,
 }
 
 impl ClientBuilder {
@@ -12,6 +34,18 @@ impl ClientBuilder {
     pub fn new() -> Self {
         Self {
             retry_config: RetryConfig::default(),
+            client_id: None,
+            api_url: None,
+            public_api_url: None,
+            oauth_config: None,
+            token: None,
+            token_store: None,
+            archive_path: None,
+            redownload_on_upgrade: false,
+            sidecar_format: None,
+            playlist_formats: Vec::new(),
+            accounts: Vec::new(),
+            lenient: false,
         }
     }
 
@@ -27,9 +61,116 @@ impl ClientBuilder {
         self
     }
 
+    /// Use a known `client_id` instead of discovering one from SoundCloud.
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Send API requests to `api_url` instead of the SoundCloud API, e.g. a local stand-in server.
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = Some(api_url.into());
+        self
+    }
+
+    /// Send uploads and track edits to `public_api_url` instead of the SoundCloud public API.
+    pub fn with_public_api_url(mut self, public_api_url: impl Into<String>) -> Self {
+        self.public_api_url = Some(public_api_url.into());
+        self
+    }
+
+    /// Authenticate requests with OAuth. Tokens are sent as `Authorization: OAuth` headers
+    /// and refreshed automatically before they expire.
+    pub fn with_oauth(mut self, oauth_config: OAuthConfig) -> Self {
+        self.oauth_config = Some(oauth_config);
+        self
+    }
+
+    /// Start with an existing OAuth token instead of authorizing again.
+    pub fn with_token(mut self, token: Token) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Load the OAuth token from `store` and write refreshed tokens back to it.
+    pub fn with_token_store(mut self, store: impl TokenStore + 'static) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Keep a download archive at `path` so already downloaded tracks are skipped.
+    pub fn with_download_archive(mut self, path: impl Into<PathBuf>) -> Self {
+        self.archive_path = Some(path.into());
+        self
+    }
+
+    /// Re-download archived tracks when a higher quality transcoding is available.
+    /// Has no effect unless a download archive is configured.
+    pub fn with_redownload_on_upgrade(mut self, redownload_on_upgrade: bool) -> Self {
+        self.redownload_on_upgrade = redownload_on_upgrade;
+        self
+    }
+
+    /// Write a JSON metadata sidecar next to every downloaded track.
+    pub fn with_sidecar(mut self, format: SidecarFormat) -> Self {
+        self.sidecar_format = Some(format);
+        self
+    }
+
+    /// Write a playlist file in `format` after `download_playlist` finishes.
+    /// Can be called more than once to write several formats.
+    pub fn with_playlist_file(mut self, format: PlaylistFormat) -> Self {
+        if !self.playlist_formats.contains(&format) {
+            self.playlist_formats.push(format);
+        }
+        self
+    }
+
+    /// Parse responses leniently: a field with an unexpected type is dropped and recorded
+    /// as a [`crate::response::FieldWarning`] on its object instead of failing the response.
+    pub fn with_lenient_parsing(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Register a named account when the client is built, see [`Client::add_account`].
+    pub fn with_account(mut self, name: impl Into<String>, config: AccountConfig) -> Self {
+        self.accounts.push((name.into(), config));
+        self
+    }
+
     /// Build the Client with the configured settings.
     pub async fn build(self) -> Result<Client, Error> {
-        Client::with_retry_config(self.retry_config).await
+        let client_id = match self.client_id {
+            Some(client_id) => client_id,
+            None => Client::get_client_id().await?,
+        };
+        let mut client = Client::with_client_id(client_id, self.retry_config);
+        if let Some(api_url) = self.api_url {
+            client.api_url = api_url;
+        }
+        if let Some(public_api_url) = self.public_api_url {
+            client.public_api_url = public_api_url;
+        }
+        client.oauth_config = self.oauth_config;
+        client.token_store = self.token_store;
+        client.init_token(self.token).await?;
+        client.sidecar_format = self.sidecar_format;
+        client.playlist_formats = self.playlist_formats;
+        client.lenient = self.lenient;
+        if let Some(path) = self.archive_path {
+            let config = ArchiveConfig {
+                path,
+                redownload_on_upgrade: self.redownload_on_upgrade,
+            };
+            let archive = DownloadArchive::open(&config.path)?;
+            client.download_archive = Some(Arc::new(Mutex::new(archive)));
+            client.archive_config = Some(config);
+        }
+        for (name, config) in self.accounts {
+            client.add_account(name, config).await?;
+        }
+        Ok(client)
     }
 }
 
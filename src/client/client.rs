@@ -1,12 +1,15 @@
+use futures::{Stream, TryStreamExt};
 use regex::Regex;
+use reqwest::Method;
 use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 
-use crate::constants::{SOUNDCLOUD_API_URL, SOUNDCLOUD_URL};
-use crate::models::client::Client;
+use crate::constants::{SOUNDCLOUD_API_URL, SOUNDCLOUD_PUBLIC_API_URL, SOUNDCLOUD_URL};
+use crate::models::client::{Client, RequestBody};
 use crate::models::config::RetryConfig;
 use crate::models::error::Error;
+use crate::models::response::{PagingCollection, from_str_lenient};
 
 impl Client {
     pub async fn new() -> Result<Self, Error> {
@@ -15,7 +18,28 @@ impl Client {
 
     pub async fn with_retry_config(retry_config: RetryConfig) -> Result<Self, Error> {
         let client_id = Self::get_client_id().await?;
-        Ok(Self { client_id: RwLock::new(client_id), retry_config })
+        Ok(Self::with_client_id(client_id, retry_config))
+    }
+
+    /// Create a client with a known `client_id`, skipping discovery.
+    pub fn with_client_id(client_id: impl Into<String>, retry_config: RetryConfig) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            client_id: RwLock::new(client_id.into()),
+            api_url: SOUNDCLOUD_API_URL.to_string(),
+            public_api_url: SOUNDCLOUD_PUBLIC_API_URL.to_string(),
+            retry_config,
+            oauth_config: None,
+            token: Mutex::new(None),
+            token_store: None,
+            archive_config: None,
+            download_archive: None,
+            sidecar_format: None,
+            playlist_formats: Vec::new(),
+            accounts: Default::default(),
+            lenient: false,
+            me_id: Mutex::new(None),
+        }
     }
 
     pub async fn refresh_client_id(&self) -> Result<(), Error> {
@@ -34,23 +58,102 @@ impl Client {
         query: Option<&Q>,
         client_id: &str,
     ) -> Result<(R, u16), Error> {
-        let url = match path {
+        Self::request_json(
+            &reqwest::Client::new(),
+            Method::GET,
+            base_url,
+            path,
+            query,
+            &RequestBody::Empty,
+            client_id,
+            None,
+            false,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn request_json<R: DeserializeOwned, Q: Serialize>(
+        http: &reqwest::Client,
+        method: Method,
+        base_url: &str,
+        path: Option<&str>,
+        query: Option<&Q>,
+        body: &RequestBody,
+        client_id: &str,
+        access_token: Option<&str>,
+        lenient: bool,
+    ) -> Result<(R, u16), Error> {
+        let url = Self::join_url(base_url, path);
+        let mut request = http.request(method, &url);
+
+        if let Some(q) = query {
+            request = request.query(q);
+        }
+        request = request.query(&[("client_id", client_id)]);
+        if let Some(token) = access_token {
+            request = request.header("Authorization", format!("OAuth {token}"));
+        }
+        request = match body {
+            RequestBody::Empty => request,
+            RequestBody::Json(json) => request.json(json),
+            RequestBody::Form(form) => request.form(form),
+        };
+
+        Self::read_json(request, lenient).await
+    }
+
+    /// Send a multipart request once. Streamed bodies cannot be replayed, so unlike
+    /// [`Client::send`] this does not retry.
+    pub(crate) async fn send_multipart<R: DeserializeOwned>(
+        &self,
+        method: Method,
+        base_url: &str,
+        path: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<R, Error> {
+        let url = Self::join_url(base_url, Some(path));
+        let client_id = self.client_id.read().await.clone();
+        let mut request = self
+            .http
+            .request(method, &url)
+            .query(&[("client_id", client_id.as_str())]);
+        if self.is_api_url(&url)
+            && let Some(token) = self.access_token().await?
+        {
+            request = request.header("Authorization", format!("OAuth {token}"));
+        }
+        let (body, _status) = Self::read_json(request.multipart(form), self.lenient).await?;
+        Ok(body)
+    }
+
+    /// Whether `url` is on the API or public API host. The OAuth token is only sent there,
+    /// never to an absolute `next_href` or CDN URL on another host.
+    fn is_api_url(&self, url: &str) -> bool {
+        let origin = |url: &str| reqwest::Url::parse(url).ok().map(|url| url.origin());
+        let Some(target) = origin(url) else {
+            return false;
+        };
+        [&self.api_url, &self.public_api_url]
+            .into_iter()
+            .any(|api_url| origin(api_url).as_ref() == Some(&target))
+    }
+
+    fn join_url(base_url: &str, path: Option<&str>) -> String {
+        match path {
             Some(path) => format!(
                 "{}/{}",
                 base_url.trim_end_matches('/'),
                 path.trim_start_matches('/')
             ),
             None => base_url.to_string(),
-        };
-
-        let client = reqwest::Client::new();
-        let mut request = client.get(&url);
-
-        if let Some(q) = query {
-            request = request.query(q);
         }
-        request = request.query(&[("client_id", client_id)]);
+    }
 
+    async fn read_json<R: DeserializeOwned>(
+        request: reqwest::RequestBuilder,
+        lenient: bool,
+    ) -> Result<(R, u16), Error> {
         let response = request.send().await.map_err(|e| {
             println!("Error sending request: {e}");
             Error::from(e)
@@ -63,10 +166,17 @@ impl Client {
             return Err(Error::new(format!("HTTP {}: {}", status, text)));
         }
 
-        // Parse JSON body for successful responses
-        let body = response.json::<R>().await.map_err(|e| {
+        // Parse JSON body for successful responses, write endpoints may answer without one
+        let text = response.text().await?;
+        let text = if text.trim().is_empty() { "null" } else { text.as_str() };
+        let body = if lenient {
+            from_str_lenient::<R>(text)
+        } else {
+            serde_json::from_str::<R>(text).map_err(Error::from)
+        }
+        .map_err(|e| {
             println!("Error parsing response: {e}");
-            Error::from(e)
+            e
         })?;
 
         Ok((body, status))
@@ -76,13 +186,148 @@ impl Client {
         &self,
         path: &str,
         query: Option<&Q>,
+    ) -> Result<R, Error> {
+        self.get_from(&self.api_url, Some(path), query).await
+    }
+
+    /// Send a request with any method through the same retry and authentication handling
+    /// as [`Client::get`].
+    pub async fn send<Q: Serialize, R: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Option<&Q>,
+        body: &RequestBody,
+    ) -> Result<R, Error> {
+        self.send_to(method, &self.api_url, Some(path), query, body)
+            .await
+    }
+
+    pub async fn post<R: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &RequestBody,
+    ) -> Result<R, Error> {
+        self.send(Method::POST, path, None::<&()>, body).await
+    }
+
+    pub async fn put<R: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &RequestBody,
+    ) -> Result<R, Error> {
+        self.send(Method::PUT, path, None::<&()>, body).await
+    }
+
+    pub async fn delete<R: DeserializeOwned>(&self, path: &str) -> Result<R, Error> {
+        self.send(Method::DELETE, path, None::<&()>, &RequestBody::Empty)
+            .await
+    }
+
+    /// Fetch the page after `page` by following its `next_href`, if there is one.
+    pub async fn get_next_page<T: DeserializeOwned>(
+        &self,
+        page: &PagingCollection<T>,
+    ) -> Result<Option<PagingCollection<T>>, Error> {
+        let Some(next_href) = page.next_href.as_deref() else {
+            return Ok(None);
+        };
+        let next: PagingCollection<T> = self.get_from(next_href, None, None::<&()>).await?;
+        Ok(Some(next))
+    }
+
+    /// Collect `first` and every page after it into a single vector.
+    pub async fn collect_pages<T: DeserializeOwned>(
+        &self,
+        first: PagingCollection<T>,
+    ) -> Result<Vec<T>, Error> {
+        self.stream_pages(Page::<(), T>::Fetched(first))
+            .try_collect()
+            .await
+    }
+
+    /// Stream every item of a paginated endpoint, fetching pages by `next_href` as they
+    /// are consumed. `query` should request `linked_partitioning`.
+    pub fn stream_items<'a, Q, T>(
+        &'a self,
+        path: String,
+        query: Option<Q>,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        Q: Serialize + 'a,
+        T: DeserializeOwned + 'a,
+    {
+        self.stream_pages(Page::First(path, query))
+    }
+
+    /// The items of `start` and of every page after it. Paging stops at the first empty
+    /// page, the API keeps sending a `next_href` past the end.
+    fn stream_pages<'a, Q, T>(
+        &'a self,
+        start: Page<Q, T>,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        Q: Serialize + 'a,
+        T: DeserializeOwned + 'a,
+    {
+        futures::stream::try_unfold(start, move |page| async move {
+            let current: PagingCollection<T> = match page {
+                Page::First(path, query) => self.get(&path, query.as_ref()).await?,
+                Page::Fetched(current) => current,
+                Page::Next(next_href) => self.get_from(&next_href, None, None::<&()>).await?,
+                Page::Done => return Ok::<_, Error>(None),
+            };
+            let next = match current.next_href {
+                Some(next_href) if !current.collection.is_empty() => Page::Next(next_href),
+                _ => Page::Done,
+            };
+            let items = futures::stream::iter(current.collection.into_iter().map(Ok));
+            Ok(Some((items, next)))
+        })
+        .try_flatten()
+    }
+
+    pub(crate) async fn get_from<Q: Serialize, R: DeserializeOwned>(
+        &self,
+        base_url: &str,
+        path: Option<&str>,
+        query: Option<&Q>,
+    ) -> Result<R, Error> {
+        self.send_to(Method::GET, base_url, path, query, &RequestBody::Empty)
+            .await
+    }
+
+    pub(crate) async fn send_to<Q: Serialize, R: DeserializeOwned>(
+        &self,
+        method: Method,
+        base_url: &str,
+        path: Option<&str>,
+        query: Option<&Q>,
+        body: &RequestBody,
     ) -> Result<R, Error> {
         let mut retries = 0;
         let max_retries = self.retry_config.max_retries;
+        let authorized = self.is_api_url(&Self::join_url(base_url, path));
 
         loop {
             let client_id = self.client_id.read().await.clone();
-            let result = Self::get_json(SOUNDCLOUD_API_URL, Some(path), query, &client_id).await;
+            let access_token = if authorized {
+                self.access_token().await?
+            } else {
+                None
+            };
+            let result = Self::request_json(
+                &self.http,
+                method.clone(),
+                base_url,
+                path,
+                query,
+                body,
+                &client_id,
+                access_token.as_deref(),
+                self.lenient,
+            )
+            .await;
 
             match result {
                 Ok((body, _status)) => {
@@ -95,6 +340,10 @@ impl Client {
                         && self.retry_config.retry_on_401 
                         && retries < max_retries {
                         retries += 1;
+                        if access_token.is_some() && self.refresh_access_token().await? {
+                            println!("Received 401, refreshed access token and retrying (attempt {retries}/{max_retries})");
+                            continue;
+                        }
                         println!("Received 401, refreshing client_id and retrying (attempt {retries}/{max_retries})");
                         self.refresh_client_id().await?;
                         continue;
@@ -127,7 +376,7 @@ impl Client {
         Ok(None)
     }
 
-    async fn get_client_id() -> Result<String, Error> {
+    pub(crate) async fn get_client_id() -> Result<String, Error> {
         let script_urls = Self::get_script_urls().await?;
         for url in script_urls {
             let client_id = Self::find_client_id(url).await?;
@@ -144,3 +393,13 @@ impl Client {
         self.get::<(), Value>("me", None).await.is_ok()
     }
 }
+
+/// Where [`Client::stream_pages`] continues from
+enum Page<Q, T> {
+    /// Request `path` with `query`
+    First(String, Option<Q>),
+    /// A page that was already requested
+    Fetched(PagingCollection<T>),
+    Next(String),
+    Done,
+}
@@ -0,0 +1,30 @@
+use crate::models::client::Client;
+use crate::models::error::Error;
+use crate::models::query::{ChartsQuery, Paging};
+use crate::models::response::{Chart, Selections};
+
+impl Client {
+    /// Top or trending tracks, optionally by genre and region.
+    pub async fn get_charts(&self, query: Option<&ChartsQuery>) -> Result<Chart, Error> {
+        let resp: Chart = self.get("charts", query).await?;
+        Ok(resp)
+    }
+
+    /// Fetch the chart page after `chart` by following its `next_href`, if there is one.
+    pub async fn get_next_chart_page(&self, chart: &Chart) -> Result<Option<Chart>, Error> {
+        let Some(next_href) = chart.next_href.as_deref() else {
+            return Ok(None);
+        };
+        let next: Chart = self.get_from(next_href, None, None::<&()>).await?;
+        Ok(Some(next))
+    }
+
+    /// The curated selections of the discover page.
+    pub async fn get_mixed_selections(
+        &self,
+        pagination: Option<&Paging>,
+    ) -> Result<Selections, Error> {
+        let resp: Selections = self.get("mixed-selections", pagination).await?;
+        Ok(resp)
+    }
+}
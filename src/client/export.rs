@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+
+use crate::models::archive::unix_timestamp;
+use crate::models::client::Client;
+use crate::models::error::Error;
+use crate::models::export::{
+    EXPORT_FORMAT_VERSION, EXPORT_INDEX_FILE_NAME, ExportIndex, ExportOptions, ExportedPlaylist,
+    ExportedTrack, write_json,
+};
+use crate::models::ids::IdentifierFor;
+use crate::models::query::Paging;
+use crate::models::response::User;
+
+const EXPORT_PAGE_SIZE: i32 = 50;
+
+impl Client {
+    /// Export everything about a user into `directory`: profile, avatar and visuals, every
+    /// track with audio and artwork, playlists with their track order, reposts and
+    /// follower/following lists. The result can be read back with [`crate::ArtistArchive::load`].
+    pub async fn export_user_archive(
+        &self,
+        identifier: impl IdentifierFor<User>,
+        directory: &str,
+        options: Option<&ExportOptions>,
+    ) -> Result<ExportIndex, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
+        let default_options = ExportOptions::default();
+        let options = options.unwrap_or(&default_options);
+        let directory = PathBuf::from(directory);
+        let paging = Paging {
+            limit: Some(EXPORT_PAGE_SIZE),
+            linked_partitioning: Some(true),
+            ..Default::default()
+        };
+
+        let user = self.get_user(identifier).await?;
+        let mut index = ExportIndex {
+            format_version: EXPORT_FORMAT_VERSION,
+            exported_at: unix_timestamp(),
+            user_id: user.id,
+            profile: PathBuf::from("profile.json"),
+            ..Default::default()
+        };
+        write_json(&directory.join(&index.profile), &user)?;
+
+        if options.include_artwork {
+            if let Some(url) = user.avatar_url.as_deref() {
+                let path = PathBuf::from("avatar.jpg");
+                match download_file(&self.http, url, &directory.join(&path)).await {
+                    Ok(()) => index.avatar = Some(path),
+                    Err(e) => index.failures.push(format!("avatar: {e}")),
+                }
+            }
+            let visuals = user.visuals.as_ref().and_then(|v| v.visuals.as_ref());
+            for (i, visual) in visuals.into_iter().flatten().enumerate() {
+                let Some(url) = visual.visual_url.as_deref() else {
+                    continue;
+                };
+                let path = PathBuf::from("visuals").join(format!("{i}.jpg"));
+                match download_file(&self.http, url, &directory.join(&path)).await {
+                    Ok(()) => index.visuals.push(path),
+                    Err(e) => index.failures.push(format!("visual {i}: {e}")),
+                }
+            }
+        }
+
+        let first = self.get_user_tracks(identifier, Some(&paging)).await?;
+        for track in self.collect_pages(first).await? {
+            let Some(id) = track.id else { continue };
+            let track_dir = PathBuf::from("tracks").join(id.to_string());
+            let mut exported = ExportedTrack {
+                id,
+                metadata: track_dir.join("track.json"),
+                ..Default::default()
+            };
+            write_json(&directory.join(&exported.metadata), &track)?;
+
+            if options.include_artwork
+                && let Some(url) = track.artwork_url.as_deref()
+            {
+                let path = track_dir.join("artwork.jpg");
+                match download_file(&self.http, url, &directory.join(&path)).await {
+                    Ok(()) => exported.artwork = Some(path),
+                    Err(e) => index.failures.push(format!("track {id} artwork: {e}")),
+                }
+            }
+
+            if options.include_audio {
+                let destination = directory.join(&track_dir);
+                let destination = destination
+                    .to_str()
+                    .expect("Failed to convert export path to string");
+                match self
+                    .download_track_with_metadata(
+                        id,
+                        options.stream_type.as_ref(),
+                        Some(destination),
+                        Some("audio"),
+                    )
+                    .await
+                {
+                    // The archive points at the earlier download, which may be elsewhere
+                    Ok(download) if !download.path.starts_with(&directory) => {
+                        let path = track_dir.join("audio.mp3");
+                        match std::fs::copy(&download.path, directory.join(&path)) {
+                            Ok(_) => exported.audio = Some(path),
+                            Err(e) => index.failures.push(format!("track {id} audio: {e}")),
+                        }
+                    }
+                    Ok(download) => {
+                        let path = &download.path;
+                        let relative = path.strip_prefix(&directory).unwrap_or(path);
+                        exported.audio = Some(relative.to_path_buf());
+                    }
+                    Err(e) => index.failures.push(format!("track {id} audio: {e}")),
+                }
+            }
+            index.tracks.push(exported);
+        }
+
+        let first = self.get_user_playlists(identifier, Some(&paging)).await?;
+        for playlist in self.collect_pages(first).await? {
+            let Some(id) = playlist.id else { continue };
+            // The listing may only contain track stubs, the full playlist keeps the ordering
+            let playlist = match self.get_playlist(id).await {
+                Ok(full) => full,
+                Err(e) => {
+                    index.failures.push(format!("playlist {id}: {e}"));
+                    playlist
+                }
+            };
+            let playlist_dir = PathBuf::from("playlists").join(id.to_string());
+            let mut exported = ExportedPlaylist {
+                id,
+                metadata: playlist_dir.join("playlist.json"),
+                artwork: None,
+            };
+            write_json(&directory.join(&exported.metadata), &playlist)?;
+
+            if options.include_artwork
+                && let Some(url) = playlist.artwork_url.as_deref()
+            {
+                let path = playlist_dir.join("artwork.jpg");
+                match download_file(&self.http, url, &directory.join(&path)).await {
+                    Ok(()) => exported.artwork = Some(path),
+                    Err(e) => index.failures.push(format!("playlist {id} artwork: {e}")),
+                }
+            }
+            index.playlists.push(exported);
+        }
+
+        if options.include_reposts {
+            let first = self.get_user_reposts(identifier, Some(&paging)).await?;
+            let reposts = self.collect_pages(first).await?;
+            let path = PathBuf::from("reposts.json");
+            write_json(&directory.join(&path), &reposts)?;
+            index.reposts = Some(path);
+        }
+
+        if options.include_followers {
+            let first = self.get_user_followers(identifier, Some(&paging)).await?;
+            let followers = self.collect_pages(first).await?;
+            let path = PathBuf::from("followers.json");
+            write_json(&directory.join(&path), &followers)?;
+            index.followers = Some(path);
+        }
+
+        if options.include_followings {
+            let first = self.get_user_followings(identifier, Some(&paging)).await?;
+            let followings = self.collect_pages(first).await?;
+            let path = PathBuf::from("followings.json");
+            write_json(&directory.join(&path), &followings)?;
+            index.followings = Some(path);
+        }
+
+        write_json(&directory.join(EXPORT_INDEX_FILE_NAME), &index)?;
+        Ok(index)
+    }
+}
+
+async fn download_file(http: &reqwest::Client, url: &str, output_path: &Path) -> Result<(), Error> {
+    let response = http.get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(Error::new(format!("HTTP {}", response.status().as_u16())));
+    }
+    let bytes = response.bytes().await?;
+    if let Some(parent) = output_path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(output_path, &bytes).await?;
+    Ok(())
+}
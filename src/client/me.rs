@@ -0,0 +1,63 @@
+use crate::models::client::Client;
+use crate::models::error::Error;
+use crate::models::ids::UserId;
+use crate::models::query::Paging;
+use crate::models::response::{Likes, PlayHistory, Playlists, Reposts, User, Users};
+
+impl Client {
+    /// The authenticated user. This and the other `get_me_*` methods need an OAuth token,
+    /// see [`crate::OAuthConfig`].
+    pub async fn get_me(&self) -> Result<User, Error> {
+        let resp: User = self.get("me", None::<&()>).await?;
+        *self.me_id.lock().await = resp.id;
+        Ok(resp)
+    }
+
+    pub async fn get_me_liked_tracks(&self, pagination: Option<&Paging>) -> Result<Likes, Error> {
+        let identifier = self.me_identifier().await?;
+        self.get_user_liked_tracks(&identifier, pagination).await
+    }
+
+    pub async fn get_me_liked_playlists(
+        &self,
+        pagination: Option<&Paging>,
+    ) -> Result<Likes, Error> {
+        let identifier = self.me_identifier().await?;
+        self.get_user_liked_playlists(&identifier, pagination).await
+    }
+
+    pub async fn get_me_followings(&self, pagination: Option<&Paging>) -> Result<Users, Error> {
+        let identifier = self.me_identifier().await?;
+        self.get_user_followings(&identifier, pagination).await
+    }
+
+    pub async fn get_me_playlists(&self, pagination: Option<&Paging>) -> Result<Playlists, Error> {
+        let identifier = self.me_identifier().await?;
+        self.get_user_playlists(&identifier, pagination).await
+    }
+
+    /// The activity feed: tracks and playlists posted or reposted by followed users.
+    pub async fn get_me_stream(&self, pagination: Option<&Paging>) -> Result<Reposts, Error> {
+        let resp: Reposts = self.get("stream", pagination).await?;
+        Ok(resp)
+    }
+
+    /// Recently played tracks, most recent first.
+    pub async fn get_me_play_history(
+        &self,
+        pagination: Option<&Paging>,
+    ) -> Result<PlayHistory, Error> {
+        let resp: PlayHistory = self.get("me/play-history/tracks", pagination).await?;
+        Ok(resp)
+    }
+
+    /// The authenticated user's id, from `/me` the first time it is needed.
+    pub(crate) async fn me_identifier(&self) -> Result<UserId, Error> {
+        if let Some(id) = *self.me_id.lock().await {
+            return Ok(id);
+        }
+        let me = self.get_me().await?;
+        me.id
+            .ok_or_else(|| Error::new("Authenticated user has no id"))
+    }
+}
@@ -1,9 +1,20 @@
+mod accounts;
 mod album;
+mod auth;
+mod autocomplete;
 mod builder;
+#[allow(clippy::module_inception)]
 mod client;
+mod discover;
+mod export;
+mod me;
 mod playlists;
 mod search;
+mod stations;
+mod sync;
 mod tracks;
+mod upload;
 mod users;
 
+pub use autocomplete::Autocompleter;
 pub use builder::ClientBuilder;
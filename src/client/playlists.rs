@@ -1,8 +1,11 @@
 use crate::models::client::Client;
-use crate::models::client::Identifier;
+use crate::models::client::RequestBody;
 use crate::models::error::Error;
-use crate::models::query::{Paging, PlaylistsQuery};
+use crate::models::ids::{IdentifierFor, TrackId, Unchecked};
+use crate::models::playlist_file::{PlaylistEntry, relative_location};
+use crate::models::query::{Paging, PlaylistParams, PlaylistsQuery};
 use crate::models::response::{Playlist, Playlists, Users};
+use serde_json::{Value, json};
 use std::path::PathBuf;
 
 impl Client {
@@ -16,8 +19,9 @@ impl Client {
 
     pub async fn get_playlist(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<Playlist>,
     ) -> Result<Playlist, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("playlists/{identifier}");
         let resp: Playlist = self.get(&url, None::<&()>).await?;
         Ok(resp)
@@ -25,20 +29,169 @@ impl Client {
 
     pub async fn get_playlist_reposters(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<Playlist>,
         pagination: Option<&Paging>,
     ) -> Result<Users, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("playlists/{identifier}/reposters");
         let resp: Users = self.get(&url, pagination).await?;
         Ok(resp)
     }
 
+    /// Create a playlist owned by the authenticated user.
+    pub async fn create_playlist(&self, params: &PlaylistParams) -> Result<Playlist, Error> {
+        let body = RequestBody::Json(json!({ "playlist": params }));
+        let resp: Playlist = self.post("playlists", &body).await?;
+        Ok(resp)
+    }
+
+    /// Update the fields set in `params`, returning the updated playlist.
+    pub async fn update_playlist(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+        params: &PlaylistParams,
+    ) -> Result<Playlist, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("playlists/{identifier}");
+        let body = RequestBody::Json(json!({ "playlist": params }));
+        let resp: Playlist = self.put(&url, &body).await?;
+        Ok(resp)
+    }
+
+    /// Replace the track list, also used to reorder it.
+    pub async fn set_playlist_tracks(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+        track_ids: &[TrackId],
+    ) -> Result<Playlist, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
+        let params = PlaylistParams {
+            tracks: Some(track_ids.to_vec()),
+            ..Default::default()
+        };
+        self.update_playlist(identifier, &params).await
+    }
+
+    /// Move the track at position `from` to position `to`.
+    pub async fn move_playlist_track(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+        from: usize,
+        to: usize,
+    ) -> Result<Playlist, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
+        let mut track_ids = self.get_playlist_track_ids(identifier).await?;
+        if from >= track_ids.len() || to >= track_ids.len() {
+            return Err(Error::new(format!(
+                "Track position out of range, playlist has {} tracks",
+                track_ids.len()
+            )));
+        }
+        let track_id = track_ids.remove(from);
+        track_ids.insert(to, track_id);
+        self.set_playlist_tracks(identifier, &track_ids).await
+    }
+
+    /// Append tracks to the end of the playlist.
+    pub async fn add_playlist_tracks(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+        track_ids: &[TrackId],
+    ) -> Result<Playlist, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
+        let mut current = self.get_playlist_track_ids(identifier).await?;
+        current.extend_from_slice(track_ids);
+        self.set_playlist_tracks(identifier, &current).await
+    }
+
+    /// Remove every occurrence of the given tracks from the playlist.
+    pub async fn remove_playlist_tracks(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+        track_ids: &[TrackId],
+    ) -> Result<Playlist, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
+        let mut current = self.get_playlist_track_ids(identifier).await?;
+        current.retain(|id| !track_ids.contains(id));
+        self.set_playlist_tracks(identifier, &current).await
+    }
+
+    pub async fn delete_playlist(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+    ) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("playlists/{identifier}");
+        let _: Value = self.delete(&url).await?;
+        Ok(())
+    }
+
+    /// Current track ids in order. Tracks without an id fall back to their URN; one with
+    /// neither fails the edit, since writing back a shorter list would drop it.
+    async fn get_playlist_track_ids(&self, identifier: &Unchecked) -> Result<Vec<TrackId>, Error> {
+        let playlist = self.get_playlist(identifier).await?;
+        playlist
+            .tracks
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(position, track)| {
+                track.track_id().ok_or_else(|| {
+                    Error::new(format!(
+                        "Playlist track at position {position} has no id or URN"
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    pub async fn like_playlist(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+    ) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/playlist_likes/{identifier}");
+        let _: Value = self.put(&url, &RequestBody::Empty).await?;
+        Ok(())
+    }
+
+    pub async fn unlike_playlist(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+    ) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/playlist_likes/{identifier}");
+        let _: Value = self.delete(&url).await?;
+        Ok(())
+    }
+
+    pub async fn repost_playlist(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+    ) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/playlist_reposts/{identifier}");
+        let _: Value = self.put(&url, &RequestBody::Empty).await?;
+        Ok(())
+    }
+
+    pub async fn unrepost_playlist(
+        &self,
+        identifier: impl IdentifierFor<Playlist>,
+    ) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/playlist_reposts/{identifier}");
+        let _: Value = self.delete(&url).await?;
+        Ok(())
+    }
+
     pub async fn download_playlist(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<Playlist>,
         destination: Option<&str>,
         playlist_name: Option<&str>,
     ) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier().unchecked();
         let playlist = self.get_playlist(identifier).await?;
 
         let playlist_title = match playlist_name {
@@ -58,21 +211,29 @@ impl Client {
             .to_str()
             .expect("Failed to convert output path to string");
         let tracks = playlist.tracks.as_ref().expect("Missing tracks");
+        let mut entries = Vec::new();
         for track in tracks {
-            let identifier = track.id.as_ref().expect("Missing track id");
-            if let Err(e) = self
-                .download_track(
-                    &Identifier::Id(*identifier),
-                    None,
-                    Some(output_path_str),
-                    None,
-                )
+            let identifier = track.id.expect("Missing track id");
+            match self
+                .download_track_with_metadata(identifier, None, Some(output_path_str), None)
                 .await
             {
-                println!("Error downloading track: {e}")
+                Ok(download) => {
+                    let location = relative_location(&download.path, &output_path)?;
+                    entries.push(PlaylistEntry::from_track(
+                        &download.track,
+                        location.to_string_lossy(),
+                    ));
+                }
+                Err(e) => println!("Error downloading track: {e}"),
             }
         }
 
+        for format in &self.playlist_formats {
+            let path = output_path.join(format!("{playlist_title}.{}", format.extension()));
+            tokio::fs::write(&path, format.render(&playlist, &entries)).await?;
+        }
+
         Ok(())
     }
 }
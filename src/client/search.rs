@@ -1,8 +1,8 @@
 use crate::{
     Client,
     models::error::Error,
-    query::{SearchAllQuery, SearchResultsQuery},
-    response::{SearchAllResponse, SearchResultsResponse},
+    query::{AutocompleteQuery, SearchAllQuery, SearchResultsQuery},
+    response::{Autocomplete, SearchAllResponse, SearchResultsResponse, Suggestions},
 };
 
 impl Client {
@@ -14,6 +14,13 @@ impl Client {
         Ok(resp)
     }
 
+    /// Suggested queries plus matching tracks, users and playlists for partial input.
+    /// See [`crate::Autocompleter`] for debounced use from interactive UIs.
+    pub async fn autocomplete(&self, query: &AutocompleteQuery) -> Result<Autocomplete, Error> {
+        let resp: Suggestions = self.get("search/autocomplete", Some(query)).await?;
+        Ok(Autocomplete::from(resp))
+    }
+
     pub async fn search_all(
         &self,
         query: Option<&SearchAllQuery>,
@@ -0,0 +1,59 @@
+use crate::models::client::Client;
+use crate::models::error::Error;
+use crate::models::ids::{IdentifierFor, StationUrn, TrackId, Urn, UserId};
+use crate::models::query::Paging;
+use crate::models::response::{Station, Track, Tracks, User};
+
+impl Client {
+    /// Load a station by URN, e.g. a parsed `User::station_urn`.
+    pub async fn get_station(&self, urn: &StationUrn) -> Result<Station, Error> {
+        let url = format!("stations/{urn}");
+        let resp: Station = self.get(&url, None::<&()>).await?;
+        Ok(resp)
+    }
+
+    /// The station of tracks similar to a track.
+    pub async fn get_track_station(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+    ) -> Result<Station, Error> {
+        let id = TrackId::try_from(&identifier.into_identifier())?;
+        self.get_station(&station_urn("track-stations", id.0)?)
+            .await
+    }
+
+    /// The radio station of an artist.
+    pub async fn get_artist_station(
+        &self,
+        identifier: impl IdentifierFor<User>,
+    ) -> Result<Station, Error> {
+        let id = UserId::try_from(&identifier.into_identifier())?;
+        self.get_station(&station_urn("artist-stations", id.0)?)
+            .await
+    }
+
+    pub async fn get_station_tracks(
+        &self,
+        urn: &StationUrn,
+        pagination: Option<&Paging>,
+    ) -> Result<Tracks, Error> {
+        let url = format!("stations/{urn}/tracks");
+        let resp: Tracks = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    /// Stream the tracks of a station, e.g. to fill a playback queue.
+    pub fn stream_station_tracks<'a>(
+        &'a self,
+        urn: &StationUrn,
+        pagination: Option<&Paging>,
+    ) -> impl futures::Stream<Item = Result<Track, Error>> + 'a {
+        let url = format!("stations/{urn}/tracks");
+        self.stream_items(url, Some(Paging::linked(pagination)))
+    }
+}
+
+/// Station URN for a track or user, e.g. `soundcloud:track-stations:123`.
+fn station_urn(kind: &str, id: i64) -> Result<StationUrn, Error> {
+    Urn::new(kind, id.to_string()).try_into()
+}
@@ -0,0 +1,211 @@
+use std::path::PathBuf;
+
+use crate::models::archive::unix_timestamp;
+use crate::models::client::Client;
+use crate::models::error::Error;
+use crate::models::ids::{IdentifierFor, Unchecked};
+use crate::models::query::Paging;
+use crate::models::response::{Track, User};
+use crate::models::sync::{
+    MirrorPlaylist, MirrorSource, MirrorTrack, SyncManifest, SyncOptions, SyncReport,
+};
+
+const SYNC_PAGE_SIZE: i32 = 50;
+const TRACKS_DIR: &str = "tracks";
+
+impl Client {
+    /// Mirror a user's uploads, reposts and playlists into `directory`.
+    ///
+    /// Only tracks that are new or whose `last_modified` changed since the previous run are
+    /// downloaded. Tracks that disappeared from the profile are marked as removed in the
+    /// manifest and their files are left in place.
+    pub async fn sync_user(
+        &self,
+        identifier: impl IdentifierFor<User>,
+        directory: &str,
+        options: Option<&SyncOptions>,
+    ) -> Result<SyncReport, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
+        let default_options = SyncOptions::default();
+        let options = options.unwrap_or(&default_options);
+        let directory = PathBuf::from(directory);
+        let previous = SyncManifest::load(&directory)?;
+        let now = unix_timestamp();
+
+        let user = self.get_user(identifier).await?;
+        let (remote_tracks, remote_playlists) = self.collect_mirror_items(identifier, options).await?;
+
+        let mut report = SyncReport::default();
+        let mut manifest = SyncManifest {
+            user: Some(user),
+            synced_at: Some(now),
+            ..Default::default()
+        };
+
+        let tracks_dir = directory.join(TRACKS_DIR);
+        for (track, sources) in remote_tracks {
+            let Some(id) = track.id else { continue };
+            let existing = previous.track(id);
+            let changed = existing.is_some_and(|e| {
+                track.last_modified.is_some() && e.last_modified != track.last_modified
+            });
+            let missing = existing.is_none_or(|e| e.path.is_none());
+
+            let mut entry = MirrorTrack {
+                id,
+                urn: track.urn.clone(),
+                title: track.title.clone(),
+                permalink_url: track.permalink_url.clone(),
+                // Only moves forward once the new version is downloaded
+                last_modified: existing.and_then(|e| e.last_modified.clone()),
+                sources,
+                path: existing.and_then(|e| e.path.clone()),
+                downloaded_at: existing.and_then(|e| e.downloaded_at),
+                removed: false,
+                removed_at: None,
+            };
+
+            if !missing && !changed {
+                report.unchanged.push(id);
+                manifest.tracks.push(entry);
+                continue;
+            }
+
+            let file_name = mirror_file_name(&track);
+            let stream_type = options.stream_type.as_ref();
+            let destination = tracks_dir.to_str().expect("Failed to convert mirror path to string");
+            match self
+                .download_track_with_metadata(id, stream_type, Some(destination), Some(&file_name))
+                .await
+            {
+                Ok(download) if download.skipped => report.skipped.push(id),
+                Ok(download) => {
+                    let path = &download.path;
+                    let relative = path.strip_prefix(&directory).unwrap_or(path);
+                    entry.path = Some(relative.to_path_buf());
+                    entry.last_modified = track.last_modified.clone();
+                    entry.downloaded_at = Some(now);
+                    if missing {
+                        report.downloaded.push(id);
+                    } else {
+                        report.updated.push(id);
+                    }
+                }
+                Err(e) => report.failed.push((id, e.to_string())),
+            }
+            manifest.tracks.push(entry);
+        }
+
+        for old in &previous.tracks {
+            if manifest.track(old.id).is_some() {
+                continue;
+            }
+            let mut old = old.clone();
+            if !old.removed {
+                old.removed = true;
+                old.removed_at = Some(now);
+                report.removed.push(old.id);
+            }
+            manifest.tracks.push(old);
+        }
+
+        manifest.playlists = remote_playlists;
+        for old in &previous.playlists {
+            if manifest.playlist(old.id).is_some() {
+                continue;
+            }
+            let mut old = old.clone();
+            if !old.removed {
+                old.removed = true;
+                old.removed_at = Some(now);
+            }
+            manifest.playlists.push(old);
+        }
+
+        manifest.save(&directory)?;
+        Ok(report)
+    }
+
+    async fn collect_mirror_items(
+        &self,
+        identifier: &Unchecked,
+        options: &SyncOptions,
+    ) -> Result<(Vec<(Track, Vec<MirrorSource>)>, Vec<MirrorPlaylist>), Error> {
+        let paging = Paging {
+            limit: Some(SYNC_PAGE_SIZE),
+            linked_partitioning: Some(true),
+            ..Default::default()
+        };
+        let mut tracks: Vec<(Track, Vec<MirrorSource>)> = Vec::new();
+        let mut playlists = Vec::new();
+
+        if options.include_uploads {
+            let first = self.get_user_tracks(identifier, Some(&paging)).await?;
+            for track in self.collect_pages(first).await? {
+                add_mirror_track(&mut tracks, track, MirrorSource::Upload);
+            }
+        }
+
+        if options.include_reposts {
+            let first = self.get_user_reposts(identifier, Some(&paging)).await?;
+            for repost in self.collect_pages(first).await? {
+                if let Some(track) = repost.track {
+                    add_mirror_track(&mut tracks, track, MirrorSource::Repost);
+                }
+            }
+        }
+
+        if options.include_playlists {
+            let first = self.get_user_playlists(identifier, Some(&paging)).await?;
+            for playlist in self.collect_pages(first).await? {
+                let Some(id) = playlist.id else { continue };
+                let playlist_tracks = playlist.tracks.unwrap_or_default();
+                let track_ids = playlist_tracks.iter().filter_map(|t| t.id).collect();
+                for track in playlist_tracks {
+                    add_mirror_track(&mut tracks, track, MirrorSource::Playlist);
+                }
+                playlists.push(MirrorPlaylist {
+                    id,
+                    urn: playlist.urn,
+                    title: playlist.title,
+                    permalink_url: playlist.permalink_url,
+                    last_modified: playlist.last_modified,
+                    track_ids,
+                    removed: false,
+                    removed_at: None,
+                });
+            }
+        }
+
+        Ok((tracks, playlists))
+    }
+}
+
+fn add_mirror_track(
+    tracks: &mut Vec<(Track, Vec<MirrorSource>)>,
+    track: Track,
+    source: MirrorSource,
+) {
+    if track.id.is_none() {
+        return;
+    }
+    match tracks.iter_mut().find(|(t, _)| t.id == track.id) {
+        Some((_, sources)) if !sources.contains(&source) => sources.push(source),
+        Some(_) => {}
+        None => tracks.push((track, vec![source])),
+    }
+}
+
+fn mirror_file_name(track: &Track) -> String {
+    let id = track.id.unwrap_or_default();
+    let name = match track.title.as_deref() {
+        Some(title) => format!("{id} - {title}"),
+        None => id.to_string(),
+    };
+    name.chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect()
+}
@@ -1,12 +1,21 @@
 use ffmpeg_sidecar::command::FfmpegCommand;
 use ffmpeg_sidecar::download;
+use futures::TryStreamExt;
+use serde_json::{Value, json};
+use std::cmp::Reverse;
 use std::path::{Path, PathBuf};
 
+use crate::models::archive::{ArchiveEntry, DownloadArchive, quality_rank, unix_timestamp};
 use crate::models::client::Client;
-use crate::models::client::Identifier;
+use crate::models::comment_file::{CommentExportOptions, CommentFileFormat};
+use crate::models::client::RequestBody;
 use crate::models::error::Error;
-use crate::models::query::{Paging, TracksQuery};
-use crate::models::response::{Track, Tracks};
+use crate::models::ids::IdentifierFor;
+use crate::models::query::{CommentsQuery, Paging, TracksQuery};
+use crate::models::response::{
+    Comment, Comments, Playlist, Playlists, Track, Tracks, User, Users,
+};
+use crate::models::sidecar::TrackSidecar;
 use crate::response::{Stream, StreamType, Transcoding, Waveform};
 
 impl Client {
@@ -14,14 +23,15 @@ impl Client {
         &self,
         query: Option<&TracksQuery>,
     ) -> Result<Tracks, Error> {
+        if let Some(query) = query {
+            query.check_filters()?;
+        }
         let tracks: Tracks = self.get("search/tracks", query).await?;
         Ok(tracks)
     }
 
-    pub async fn get_track(
-        &self,
-        identifier: &Identifier,
-    ) -> Result<Track, Error> {
+    pub async fn get_track(&self, identifier: impl IdentifierFor<Track>) -> Result<Track, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("tracks/{identifier}");
         let resp: Track = self.get(&url, None::<&()>).await?;
         Ok(resp)
@@ -29,21 +39,214 @@ impl Client {
 
     pub async fn get_track_related(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<Track>,
         pagination: Option<&Paging>,
     ) -> Result<Tracks, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("tracks/{identifier}/related");
         let resp: Tracks = self.get(&url, pagination).await?;
         Ok(resp)
     }
 
+    /// Users who liked the track.
+    pub async fn get_track_likers(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> Result<Users, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/likers");
+        let resp: Users = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    pub fn stream_track_likers<'a>(
+        &'a self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> impl futures::Stream<Item = Result<User, Error>> + 'a {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/likers");
+        self.stream_items(url, Some(Paging::linked(pagination)))
+    }
+
+    /// Users who reposted the track.
+    pub async fn get_track_reposters(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> Result<Users, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/reposters");
+        let resp: Users = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    pub fn stream_track_reposters<'a>(
+        &'a self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> impl futures::Stream<Item = Result<User, Error>> + 'a {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/reposters");
+        self.stream_items(url, Some(Paging::linked(pagination)))
+    }
+
+    /// Playlists containing the track, albums excluded.
+    pub async fn get_track_playlists(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> Result<Playlists, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/playlists_without_albums");
+        let resp: Playlists = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    pub fn stream_track_playlists<'a>(
+        &'a self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> impl futures::Stream<Item = Result<Playlist, Error>> + 'a {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/playlists_without_albums");
+        self.stream_items(url, Some(Paging::linked(pagination)))
+    }
+
+    /// Albums containing the track.
+    pub async fn get_track_albums(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> Result<Playlists, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/albums");
+        let resp: Playlists = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    pub fn stream_track_albums<'a>(
+        &'a self,
+        identifier: impl IdentifierFor<Track>,
+        pagination: Option<&Paging>,
+    ) -> impl futures::Stream<Item = Result<Playlist, Error>> + 'a {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/albums");
+        self.stream_items(url, Some(Paging::linked(pagination)))
+    }
+
+    pub async fn get_track_comments(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        query: Option<&CommentsQuery>,
+    ) -> Result<Comments, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/comments");
+        let resp: Comments = self.get(&url, query).await?;
+        Ok(resp)
+    }
+
+    /// Stream all comments of a track, fetching further pages as needed.
+    pub fn stream_track_comments<'a>(
+        &'a self,
+        identifier: impl IdentifierFor<Track>,
+        query: Option<&CommentsQuery>,
+    ) -> impl futures::Stream<Item = Result<Comment, Error>> + 'a {
+        let identifier = &identifier.into_identifier();
+        let mut query = query.cloned().unwrap_or_default();
+        query.linked_partitioning = Some(true);
+        self.stream_items(format!("tracks/{identifier}/comments"), Some(query))
+    }
+
+    /// Write the comments of a track as timed text files next to the audio at `audio_path`,
+    /// one per format, e.g. `song.mp3` -> `song.srt`. Returns the written paths.
+    pub async fn export_track_comments(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        audio_path: impl AsRef<Path>,
+        formats: &[CommentFileFormat],
+        options: Option<&CommentExportOptions>,
+    ) -> Result<Vec<PathBuf>, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
+        let track = self.get_track(identifier).await?;
+        let comments: Vec<Comment> = self
+            .stream_track_comments(identifier, None)
+            .try_collect()
+            .await?;
+        let options = options.cloned().unwrap_or_default();
+
+        let mut paths = Vec::new();
+        for format in formats {
+            let path = format.path_for(audio_path.as_ref());
+            tokio::fs::write(&path, format.render(&track, &comments, &options)).await?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    pub async fn like_track(&self, identifier: impl IdentifierFor<Track>) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/track_likes/{identifier}");
+        let _: Value = self.put(&url, &RequestBody::Empty).await?;
+        Ok(())
+    }
+
+    pub async fn unlike_track(&self, identifier: impl IdentifierFor<Track>) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/track_likes/{identifier}");
+        let _: Value = self.delete(&url).await?;
+        Ok(())
+    }
+
+    pub async fn repost_track(&self, identifier: impl IdentifierFor<Track>) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/track_reposts/{identifier}");
+        let _: Value = self.put(&url, &RequestBody::Empty).await?;
+        Ok(())
+    }
+
+    pub async fn unrepost_track(&self, identifier: impl IdentifierFor<Track>) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/track_reposts/{identifier}");
+        let _: Value = self.delete(&url).await?;
+        Ok(())
+    }
+
+    /// Post a comment on a track, optionally anchored to a playback position in milliseconds.
+    pub async fn post_track_comment(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        body: &str,
+        timestamp: Option<i64>,
+    ) -> Result<Comment, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("tracks/{identifier}/comments");
+        let payload = json!({ "comment": { "body": body, "timestamp": timestamp } });
+        let resp: Comment = self.post(&url, &RequestBody::Json(payload)).await?;
+        Ok(resp)
+    }
+
     pub async fn download_track(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<Track>,
         stream_type: Option<&StreamType>,
         destination: Option<&str>,
         filename: Option<&str>,
     ) -> Result<(), Error> {
+        self.download_track_with_metadata(identifier, stream_type, destination, filename)
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn download_track_with_metadata(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        stream_type: Option<&StreamType>,
+        destination: Option<&str>,
+        filename: Option<&str>,
+    ) -> Result<TrackDownload, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
         let track = self.get_track(identifier).await?;
 
         let stream = match stream_type {
@@ -64,14 +267,31 @@ impl Client {
             Some(destination) => PathBuf::from(destination).join(format!("{title}.mp3")),
             None => PathBuf::from(format!("{title}.mp3")),
         };
-        if let Some(parent) = output_path.parent() {
-            if !parent.exists() {
-                std::fs::create_dir_all(parent)?;
-            }
+        if let Some(parent) = output_path.parent()
+            && !parent.exists()
+        {
+            std::fs::create_dir_all(parent)?;
         }
 
-        let transcoding = self.get_transcoding_by_stream_type(&track, stream).await?;
-        let stream_url = self.get_stream_url(identifier, Some(stream)).await?;
+        let archived = self.find_archived(&track).await;
+        if let Some(entry) = &archived
+            && !self.redownload_on_upgrade()
+        {
+            println!("Skipping {title}: already in download archive");
+            return Ok(TrackDownload::skipped(track, entry));
+        }
+
+        // Looking for an upgrade means looking at the best transcoding, not the first one
+        let transcoding = self
+            .get_transcoding_by_stream_type(&track, stream, archived.is_some())
+            .await?;
+        if let Some(entry) = &archived
+            && !DownloadArchive::is_upgrade(entry, &transcoding)
+        {
+            println!("Skipping {title}: no higher quality transcoding available");
+            return Ok(TrackDownload::skipped(track, entry));
+        }
+        let stream_url = self.resolve_stream_url(&transcoding).await?;
 
         match transcoding
             .format
@@ -87,41 +307,104 @@ impl Client {
             _ => return Err(Error::new("Invalid Stream Type")),
         }
 
-        Ok(())
+        if let Some(format) = self.sidecar_format {
+            let sidecar = TrackSidecar {
+                track: track.clone(),
+                transcoding: transcoding.clone(),
+                stream_type: stream.clone(),
+                downloaded_at: unix_timestamp(),
+                source_url: track.permalink_url.clone(),
+            };
+            sidecar.save(format.path_for(&output_path))?;
+        }
+        self.record_archived(&track, &transcoding, &output_path).await?;
+        // An upgrade saved under a new name replaces the lower quality file
+        if let Some(entry) = &archived
+            && entry.output_path != output_path
+            && entry.output_path.exists()
+        {
+            std::fs::remove_file(&entry.output_path)?;
+        }
+        Ok(TrackDownload {
+            track,
+            path: output_path,
+            skipped: false,
+        })
+    }
+
+    async fn find_archived(&self, track: &Track) -> Option<ArchiveEntry> {
+        let archive = self.download_archive.as_ref()?;
+        archive.lock().await.find(track).cloned()
+    }
+
+    fn redownload_on_upgrade(&self) -> bool {
+        self.archive_config
+            .as_ref()
+            .is_some_and(|c| c.redownload_on_upgrade)
+    }
+
+    async fn record_archived(
+        &self,
+        track: &Track,
+        transcoding: &Transcoding,
+        output_path: &Path,
+    ) -> Result<(), Error> {
+        let Some(archive) = self.download_archive.as_ref() else {
+            return Ok(());
+        };
+        let entry = ArchiveEntry::from_download(track, transcoding, output_path)?;
+        let mut archive = archive.lock().await;
+        archive.record(entry);
+        archive.save()
     }
 
     pub async fn get_track_waveform(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<Track>,
     ) -> Result<Waveform, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
         let track = self.get_track(identifier).await?;
         let waveform_url = track.waveform_url.as_ref().expect("Missing waveform URL");
-        let response = reqwest::get(waveform_url).await?;
+        let response = self.http.get(waveform_url).send().await?;
         let waveform: Waveform = response.json::<Waveform>().await?;
         Ok(waveform)
     }
 
     pub async fn get_stream_url(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<Track>,
         stream_type: Option<&StreamType>,
     ) -> Result<String, Error> {
+        let identifier = &identifier.into_identifier().unchecked();
         let track = self.get_track(identifier).await?;
         let stream = match stream_type {
             Some(stream_type) => stream_type,
             None => &StreamType::Progressive,
         };
-        let transcoding = self.get_transcoding_by_stream_type(&track, stream).await?;
-        let path = transcoding.url.as_ref().ok_or_else(|| Error::new("Missing transcoding URL"))?;
-        let client_id = self.get_client_id_value().await;
-        let (stream, _): (Stream, _) = Self::get_json(path, None, None::<&()>, &client_id).await?;
-        stream.url.ok_or_else(|| Error::new("Missing resolved stream URL"))
+        let transcoding = self
+            .get_transcoding_by_stream_type(&track, stream, false)
+            .await?;
+        self.resolve_stream_url(&transcoding).await
+    }
+
+    async fn resolve_stream_url(&self, transcoding: &Transcoding) -> Result<String, Error> {
+        let path = transcoding
+            .url
+            .as_ref()
+            .ok_or_else(|| Error::new("Missing transcoding URL"))?;
+        let stream: Stream = self.get_from(path, None, None::<&()>).await?;
+        stream
+            .url
+            .ok_or_else(|| Error::new("Missing resolved stream URL"))
     }
 
+    /// The first transcoding of `stream_type` that resolves to a stream, or with
+    /// `best_quality` the highest quality one.
     async fn get_transcoding_by_stream_type(
         &self,
         track: &Track,
         stream_type: &StreamType,
+        best_quality: bool,
     ) -> Result<Transcoding, Error> {
         let transcodings = track
             .media
@@ -134,8 +417,13 @@ impl Client {
             return Err(Error::new("No available download options"));
         }
 
+        let mut candidates: Vec<&Transcoding> = transcodings.iter().collect();
+        if best_quality {
+            candidates.sort_by_key(|t| Reverse(quality_rank(t.quality.as_deref())));
+        }
+
         let transcoding: Option<Transcoding> = {
-            for t in transcodings {
+            for t in candidates {
                 let protocol = match t.format.as_ref().and_then(|f| f.protocol.as_ref()) {
                     Some(p) => p,
                     None => continue,
@@ -149,9 +437,7 @@ impl Client {
                     None => continue,
                 };
 
-                let client_id = self.get_client_id_value().await;
-                let (stream, _): (Stream, _) =
-                    Self::get_json(path, None, None::<&()>, &client_id).await?;
+                let stream: Stream = self.get_from(path, None, None::<&()>).await?;
                 if stream.url.is_some() {
                     return Ok(t.clone());
                 }
@@ -166,7 +452,7 @@ impl Client {
         stream_url: &str,
         output_path: &Path,
     ) -> Result<(), Error> {
-        let response = reqwest::get(stream_url).await?;
+        let response = self.http.get(stream_url).send().await?;
         let bytes = response.bytes().await?;
         tokio::fs::write(output_path, &bytes).await?;
         Ok(())
@@ -197,3 +483,21 @@ impl Client {
         Ok(())
     }
 }
+
+/// Outcome of [`Client::download_track_with_metadata`]
+pub(crate) struct TrackDownload {
+    pub track: Track,
+    pub path: PathBuf,
+    /// The download archive already had the track, so nothing was written
+    pub skipped: bool,
+}
+
+impl TrackDownload {
+    fn skipped(track: Track, entry: &ArchiveEntry) -> Self {
+        Self {
+            track,
+            path: entry.output_path.clone(),
+            skipped: true,
+        }
+    }
+}
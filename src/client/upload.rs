@@ -0,0 +1,164 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Method;
+use reqwest::multipart::{Form, Part};
+use serde_json::{Value, json};
+use tokio::io::AsyncReadExt;
+
+use crate::models::client::{Client, RequestBody};
+use crate::models::error::Error;
+use crate::models::ids::IdentifierFor;
+use crate::models::query::TrackParams;
+use crate::models::response::Track;
+use crate::models::upload::{
+    UPLOAD_CHUNK_SIZE, UPLOAD_POLL_INTERVAL_SECS, UPLOAD_PROCESSING_TIMEOUT_SECS, UploadProgress,
+};
+
+impl Client {
+    /// Upload an audio file with its metadata and optional artwork, then wait until
+    /// SoundCloud has finished processing it. Requires an OAuth token.
+    ///
+    /// The audio is streamed from disk, `on_progress` is called after every chunk sent.
+    pub async fn upload_track<F>(
+        &self,
+        audio: impl AsRef<Path>,
+        artwork: Option<&Path>,
+        params: &TrackParams,
+        on_progress: F,
+    ) -> Result<Track, Error>
+    where
+        F: Fn(UploadProgress) + Send + Sync + 'static,
+    {
+        let mut form = Form::new().part(
+            "track[asset_data]",
+            audio_part(audio.as_ref(), on_progress).await?,
+        );
+        if let Some(artwork) = artwork {
+            let bytes = tokio::fs::read(artwork).await?;
+            let part = Part::bytes(bytes).file_name(file_name(artwork));
+            form = form.part("track[artwork_data]", part);
+        }
+        for (name, value) in track_fields(params)? {
+            form = form.text(format!("track[{name}]"), value);
+        }
+
+        let track: Track = self
+            .send_multipart(Method::POST, &self.public_api_url, "tracks", form)
+            .await?;
+        let id = track
+            .id
+            .ok_or_else(|| Error::new("Uploaded track has no id"))?;
+        self.wait_for_track_processing(
+            id,
+            Duration::from_secs(UPLOAD_POLL_INTERVAL_SECS),
+            Duration::from_secs(UPLOAD_PROCESSING_TIMEOUT_SECS),
+        )
+        .await
+    }
+
+    /// Poll a track every `poll_interval` until it is no longer processing.
+    /// Fails if processing failed or the track is still processing after `timeout`.
+    pub async fn wait_for_track_processing(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Track, Error> {
+        let identifier = &identifier.into_identifier();
+        let path = format!("tracks/{identifier}");
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let track: Track = self
+                .get_from(&self.public_api_url, Some(&path), None::<&()>)
+                .await?;
+            match track.state.as_deref() {
+                Some("processing") if tokio::time::Instant::now() + poll_interval > deadline => {
+                    return Err(Error::new(format!(
+                        "Track {identifier} is still processing after {timeout:?}"
+                    )));
+                }
+                Some("processing") => tokio::time::sleep(poll_interval).await,
+                Some("failed") => {
+                    return Err(Error::new(format!(
+                        "Processing of track {identifier} failed"
+                    )));
+                }
+                _ => return Ok(track),
+            }
+        }
+    }
+
+    /// Update the metadata fields set in `params`, returning the updated track.
+    pub async fn update_track(
+        &self,
+        identifier: impl IdentifierFor<Track>,
+        params: &TrackParams,
+    ) -> Result<Track, Error> {
+        let identifier = &identifier.into_identifier();
+        let path = format!("tracks/{identifier}");
+        let body = RequestBody::Json(json!({ "track": params }));
+        self.send_to(
+            Method::PUT,
+            &self.public_api_url,
+            Some(&path),
+            None::<&()>,
+            &body,
+        )
+        .await
+    }
+}
+
+/// Multipart part streaming the audio file from disk in chunks.
+async fn audio_part<F>(path: &Path, on_progress: F) -> Result<Part, Error>
+where
+    F: Fn(UploadProgress) + Send + Sync + 'static,
+{
+    let file = tokio::fs::File::open(path).await?;
+    let total_bytes = file.metadata().await?.len();
+    let on_progress = Arc::new(on_progress);
+
+    let chunks = futures::stream::unfold((file, 0u64), move |(mut file, bytes_sent)| {
+        let on_progress = on_progress.clone();
+        async move {
+            let mut buf = vec![0u8; UPLOAD_CHUNK_SIZE];
+            match file.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    let bytes_sent = bytes_sent + n as u64;
+                    on_progress(UploadProgress {
+                        bytes_sent,
+                        total_bytes,
+                    });
+                    Some((Ok(buf), (file, bytes_sent)))
+                }
+                Err(e) => Some((Err(e), (file, bytes_sent))),
+            }
+        }
+    });
+
+    let body = reqwest::Body::wrap_stream(chunks);
+    Ok(Part::stream_with_length(body, total_bytes).file_name(file_name(path)))
+}
+
+/// Metadata as multipart text fields, booleans and numbers are sent as their JSON text.
+fn track_fields(params: &TrackParams) -> Result<Vec<(String, String)>, Error> {
+    let Value::Object(fields) = serde_json::to_value(params)? else {
+        return Ok(Vec::new());
+    };
+    Ok(fields
+        .into_iter()
+        .map(|(name, value)| match value {
+            Value::String(text) => (name, text),
+            other => (name, other.to_string()),
+        })
+        .collect())
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "upload".to_string())
+}
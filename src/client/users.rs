@@ -1,8 +1,13 @@
+use serde_json::Value;
+
 use crate::models::client::Client;
-use crate::models::client::Identifier;
+use crate::models::client::{Identifier, RequestBody};
 use crate::models::error::Error;
+use crate::models::ids::{IdentifierFor, UserId};
 use crate::models::query::{Paging, UsersQuery};
-use crate::models::response::{Playlists, Reposts, Tracks, User, Users};
+use crate::models::response::{
+    Likes, Playlists, Reposts, Spotlight, Tracks, User, Users, WebProfile,
+};
 
 impl Client {
     pub async fn search_users(&self, query: Option<&UsersQuery>) -> Result<Users, Error> {
@@ -10,10 +15,8 @@ impl Client {
         Ok(resp)
     }
 
-    pub async fn get_user(
-        &self,
-        identifier: &Identifier,
-    ) -> Result<User, Error> {
+    pub async fn get_user(&self, identifier: impl IdentifierFor<User>) -> Result<User, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("users/{identifier}");
         let resp: User = self.get(&url, None::<&()>).await?;
         Ok(resp)
@@ -21,9 +24,10 @@ impl Client {
 
     pub async fn get_user_followers(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<User>,
         pagination: Option<&Paging>,
     ) -> Result<Users, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("users/{identifier}/followers");
         let resp: Users = self.get(&url, pagination).await?;
         Ok(resp)
@@ -31,9 +35,10 @@ impl Client {
 
     pub async fn get_user_followings(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<User>,
         pagination: Option<&Paging>,
     ) -> Result<Users, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("users/{identifier}/followings");
         let resp: Users = self.get(&url, pagination).await?;
         Ok(resp)
@@ -41,9 +46,10 @@ impl Client {
 
     pub async fn get_user_playlists(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<User>,
         pagination: Option<&Paging>,
     ) -> Result<Playlists, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("users/{identifier}/playlists");
         let resp: Playlists = self.get(&url, pagination).await?;
         Ok(resp)
@@ -51,9 +57,10 @@ impl Client {
 
     pub async fn get_user_tracks(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<User>,
         pagination: Option<&Paging>,
     ) -> Result<Tracks, Error> {
+        let identifier = &identifier.into_identifier();
         let url = format!("users/{identifier}/tracks");
         let resp: Tracks = self.get(&url, pagination).await?;
         Ok(resp)
@@ -61,19 +68,98 @@ impl Client {
 
     pub async fn get_user_reposts(
         &self,
-        identifier: &Identifier,
+        identifier: impl IdentifierFor<User>,
         pagination: Option<&Paging>,
     ) -> Result<Reposts, Error> {
-        let id = match identifier {
-            Identifier::Id(id) => id.to_string(),
-            Identifier::Urn(urn) => urn
-                .split(':')
-                .last()
-                .expect("Could not extract ID from URN")
-                .to_owned(),
-        };
-        let url = format!("stream/users/{}/reposts", id);
+        let identifier = &identifier.into_identifier();
+        let url = format!("stream/users/{}/reposts", UserId::try_from(identifier)?);
         let resp: Reposts = self.get(&url, pagination).await?;
         Ok(resp)
     }
+
+    pub async fn get_user_liked_tracks(
+        &self,
+        identifier: impl IdentifierFor<User>,
+        pagination: Option<&Paging>,
+    ) -> Result<Likes, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("users/{identifier}/track_likes");
+        let resp: Likes = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_user_liked_playlists(
+        &self,
+        identifier: impl IdentifierFor<User>,
+        pagination: Option<&Paging>,
+    ) -> Result<Likes, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("users/{identifier}/playlist_likes");
+        let resp: Likes = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_user_albums(
+        &self,
+        identifier: impl IdentifierFor<User>,
+        pagination: Option<&Paging>,
+    ) -> Result<Playlists, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("users/{identifier}/albums");
+        let resp: Playlists = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    /// Tracks and playlists pinned to the top of the user's profile.
+    pub async fn get_user_spotlight(
+        &self,
+        identifier: impl IdentifierFor<User>,
+        pagination: Option<&Paging>,
+    ) -> Result<Spotlight, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("users/{identifier}/spotlight");
+        let resp: Spotlight = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    /// The user's most played tracks.
+    pub async fn get_user_top_tracks(
+        &self,
+        identifier: impl IdentifierFor<User>,
+        pagination: Option<&Paging>,
+    ) -> Result<Tracks, Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("users/{identifier}/toptracks");
+        let resp: Tracks = self.get(&url, pagination).await?;
+        Ok(resp)
+    }
+
+    /// External links on the user's profile. This endpoint is not paginated.
+    pub async fn get_user_web_profiles(
+        &self,
+        identifier: impl IdentifierFor<User>,
+    ) -> Result<Vec<WebProfile>, Error> {
+        let identifier = &identifier.into_identifier();
+        let urn = match identifier {
+            Identifier::Id(id) => format!("soundcloud:users:{id}"),
+            Identifier::Urn(urn) => urn.clone(),
+        };
+        let url = format!("users/{urn}/web-profiles");
+        let resp: Vec<WebProfile> = self.get(&url, None::<&()>).await?;
+        Ok(resp)
+    }
+
+    pub async fn follow_user(&self, identifier: impl IdentifierFor<User>) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/followings/{identifier}");
+        let _: Value = self.post(&url, &RequestBody::Empty).await?;
+        Ok(())
+    }
+
+    pub async fn unfollow_user(&self, identifier: impl IdentifierFor<User>) -> Result<(), Error> {
+        let identifier = &identifier.into_identifier();
+        let url = format!("me/followings/{identifier}");
+        let _: Value = self.delete(&url).await?;
+        Ok(())
+    }
 }
@@ -1,2 +1,6 @@
 pub const SOUNDCLOUD_URL: &str = "https://soundcloud.com";
 pub const SOUNDCLOUD_API_URL: &str = "https://api-v2.soundcloud.com";
+/// Public API, used for uploads and track edits
+pub const SOUNDCLOUD_PUBLIC_API_URL: &str = "https://api.soundcloud.com";
+pub const SOUNDCLOUD_AUTHORIZE_URL: &str = "https://secure.soundcloud.com/authorize";
+pub const SOUNDCLOUD_TOKEN_URL: &str = "https://secure.soundcloud.com/oauth/token";
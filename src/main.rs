@@ -1,6 +1,6 @@
 use std::error::Error;
 
-use soundcloud_rs::{ClientBuilder, Identifier, query::TracksQuery};
+use soundcloud_rs::{ClientBuilder, query::TracksQuery};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
@@ -20,12 +20,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let first_track = tracks.collection.first().expect("No tracks found");
     let first_track_id = first_track.id.expect("No track id found");
     client
-        .download_track(
-            &Identifier::Id(first_track_id),
-            None,
-            Some("./downloads"),
-            None,
-        )
+        .download_track(first_track_id, None, Some("./downloads"), None)
         .await?;
     Ok(())
 }
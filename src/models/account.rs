@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use crate::models::auth::{OAuthConfig, Token};
+use crate::models::token_store::TokenStore;
+
+/// Credentials of a named account registered with [`crate::Client::add_account`]
+#[derive(Debug, Clone, Default)]
+pub struct AccountConfig {
+    /// Defaults to the `client_id` of the client the account is added to
+    pub client_id: Option<String>,
+    pub oauth_config: Option<OAuthConfig>,
+    pub token: Option<Token>,
+    pub token_store: Option<Arc<dyn TokenStore>>,
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::models::auth::random_string;
+use crate::models::error::Error;
+use crate::models::ids::TrackId;
+use crate::response::{StreamType, Track, Transcoding};
+
+/// A single downloaded track recorded in a [`DownloadArchive`]
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ArchiveEntry {
+    pub track_id: Option<TrackId>,
+    pub urn: Option<String>,
+    pub preset: Option<String>,
+    pub quality: Option<String>,
+    pub protocol: Option<StreamType>,
+    pub output_path: PathBuf,
+    pub size: u64,
+    /// Hex encoded SHA-256 of the downloaded file
+    pub checksum: String,
+    /// Seconds since the UNIX epoch
+    pub downloaded_at: u64,
+}
+
+impl ArchiveEntry {
+    /// Build an entry for a file that has just been written to `output_path`.
+    pub fn from_download(
+        track: &Track,
+        transcoding: &Transcoding,
+        output_path: &Path,
+    ) -> Result<Self, Error> {
+        let bytes = std::fs::read(output_path)?;
+        let checksum = Sha256::digest(&bytes)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        let downloaded_at = unix_timestamp();
+
+        Ok(Self {
+            track_id: track.id,
+            urn: track.urn.clone(),
+            preset: transcoding.preset.clone(),
+            quality: transcoding.quality.clone(),
+            protocol: transcoding.format.as_ref().and_then(|f| f.protocol.clone()),
+            output_path: output_path.to_path_buf(),
+            size: bytes.len() as u64,
+            checksum,
+            downloaded_at,
+        })
+    }
+
+    fn matches(&self, track_id: Option<TrackId>, urn: Option<&str>) -> bool {
+        let id_match = track_id.is_some() && self.track_id == track_id;
+        let urn_match = urn.is_some() && self.urn.as_deref() == urn;
+        id_match || urn_match
+    }
+}
+
+/// Persistent record of downloaded tracks, keyed by track id and URN so that
+/// files are recognised even after they have been renamed or moved.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct DownloadArchive {
+    #[serde(skip)]
+    pub path: PathBuf,
+    pub entries: Vec<ArchiveEntry>,
+}
+
+impl DownloadArchive {
+    /// Load the archive at `path`, or start an empty one if the file does not exist yet.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let mut archive = if path.exists() {
+            let text = std::fs::read_to_string(&path)?;
+            serde_json::from_str::<DownloadArchive>(&text)?
+        } else {
+            DownloadArchive::default()
+        };
+        archive.path = path;
+        Ok(archive)
+    }
+
+    /// Write the archive back to disk, replacing the previous file atomically.
+    pub fn save(&self) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+            && !parent.exists()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        write_atomically(&self.path, &serde_json::to_vec_pretty(self)?)
+    }
+
+    /// Find the entry recorded for `track`, matching on id or URN.
+    pub fn find(&self, track: &Track) -> Option<&ArchiveEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.matches(track.id, track.urn.as_deref()))
+    }
+
+    /// Insert `entry`, replacing any previous entry for the same track.
+    pub fn record(&mut self, entry: ArchiveEntry) {
+        let urn = entry.urn.clone();
+        self.entries
+            .retain(|e| !e.matches(entry.track_id, urn.as_deref()));
+        self.entries.push(entry);
+    }
+
+    /// Whether `transcoding` is a better quality than what was archived for `entry`.
+    pub fn is_upgrade(entry: &ArchiveEntry, transcoding: &Transcoding) -> bool {
+        quality_rank(transcoding.quality.as_deref()) > quality_rank(entry.quality.as_deref())
+    }
+}
+
+/// Seconds since the UNIX epoch, as stored in archives and manifests
+pub(crate) fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Replace the file at `path` with `bytes` through a temporary file next to it, unique so
+/// concurrent writers never share one.
+pub(crate) fn write_atomically(path: &Path, bytes: &[u8]) -> Result<(), Error> {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(format!(".{}.{}.tmp", std::process::id(), random_string(8)));
+    let tmp_path = path.with_file_name(name);
+    std::fs::write(&tmp_path, bytes)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+pub(crate) fn quality_rank(quality: Option<&str>) -> u8 {
+    match quality {
+        Some("hq") => 2,
+        Some("sq") => 1,
+        _ => 0,
+    }
+}
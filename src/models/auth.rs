@@ -0,0 +1,228 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use rand::Rng;
+use rand::distr::Alphanumeric;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::constants::{SOUNDCLOUD_AUTHORIZE_URL, SOUNDCLOUD_TOKEN_URL};
+use crate::models::archive::unix_timestamp;
+use crate::models::error::Error;
+
+/// Refresh access tokens this many seconds before they expire
+pub const TOKEN_EXPIRY_MARGIN_SECS: u64 = 60;
+
+/// OAuth application credentials and endpoints
+#[derive(Debug, Clone)]
+pub struct OAuthConfig {
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    /// Loopback redirect registered for the application, e.g. `http://127.0.0.1:8080/callback`
+    pub redirect_uri: Option<String>,
+    pub scope: Option<String>,
+    pub flow: OAuthFlow,
+    pub authorize_url: String,
+    pub token_url: String,
+}
+
+impl OAuthConfig {
+    /// Configuration for the authorization code + PKCE flow with a loopback `redirect_uri`.
+    pub fn authorization_code(
+        client_id: impl Into<String>,
+        client_secret: Option<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret,
+            redirect_uri: Some(redirect_uri.into()),
+            scope: None,
+            flow: OAuthFlow::AuthorizationCode,
+            authorize_url: SOUNDCLOUD_AUTHORIZE_URL.to_string(),
+            token_url: SOUNDCLOUD_TOKEN_URL.to_string(),
+        }
+    }
+
+    /// Configuration for the client credentials flow.
+    pub fn client_credentials(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: Some(client_secret.into()),
+            redirect_uri: None,
+            scope: None,
+            flow: OAuthFlow::ClientCredentials,
+            authorize_url: SOUNDCLOUD_AUTHORIZE_URL.to_string(),
+            token_url: SOUNDCLOUD_TOKEN_URL.to_string(),
+        }
+    }
+
+    /// Build the URL the user has to visit to authorize the application.
+    pub fn authorization_url(&self, pkce: &PkceChallenge, state: &str) -> Result<String, Error> {
+        let redirect_uri = self
+            .redirect_uri
+            .as_deref()
+            .ok_or_else(|| Error::new("Missing OAuth redirect_uri"))?;
+        let mut params = vec![
+            ("client_id", self.client_id.as_str()),
+            ("redirect_uri", redirect_uri),
+            ("response_type", "code"),
+            ("code_challenge", pkce.challenge.as_str()),
+            ("code_challenge_method", "S256"),
+            ("state", state),
+        ];
+        if let Some(scope) = self.scope.as_deref() {
+            params.push(("scope", scope));
+        }
+        let url = reqwest::Url::parse_with_params(&self.authorize_url, &params)
+            .map_err(|e| Error::from_error("Invalid OAuth authorize URL", e))?;
+        Ok(url.to_string())
+    }
+
+    /// Exchange an authorization code for a token (authorization code + PKCE flow).
+    pub async fn exchange_code(&self, code: &str, code_verifier: &str) -> Result<Token, Error> {
+        let redirect_uri = self
+            .redirect_uri
+            .as_deref()
+            .ok_or_else(|| Error::new("Missing OAuth redirect_uri"))?;
+        self.request_token(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("code_verifier", code_verifier),
+            ("redirect_uri", redirect_uri),
+        ])
+        .await
+    }
+
+    /// Request an application token (client credentials flow).
+    pub async fn request_client_credentials(&self) -> Result<Token, Error> {
+        if self.client_secret.is_none() {
+            return Err(Error::new(
+                "Client credentials flow requires a client_secret",
+            ));
+        }
+        self.request_token(&[("grant_type", "client_credentials")])
+            .await
+    }
+
+    /// Exchange a refresh token for a new access token.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<Token, Error> {
+        self.request_token(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+
+    async fn request_token(&self, params: &[(&str, &str)]) -> Result<Token, Error> {
+        let mut form: Vec<(&str, &str)> = params.to_vec();
+        form.push(("client_id", &self.client_id));
+
+        let client = reqwest::Client::new();
+        let mut request = client
+            .post(&self.token_url)
+            .header("Accept", "application/json; charset=utf-8");
+        if let Some(secret) = self.client_secret.as_deref() {
+            request = request.basic_auth(&self.client_id, Some(secret));
+        }
+        let response = request.form(&form).send().await?;
+
+        let status = response.status().as_u16();
+        if !response.status().is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(Error::new(format!(
+                "OAuth token request failed: HTTP {status}: {text}"
+            )));
+        }
+        let token: TokenResponse = response.json().await?;
+        Ok(Token::from(token))
+    }
+}
+
+/// Which OAuth flow the client uses to obtain tokens on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OAuthFlow {
+    /// Tokens come from [`crate::Client::authorize_with_pkce`]
+    AuthorizationCode,
+    /// Tokens are requested with the client secret whenever needed
+    ClientCredentials,
+}
+
+/// An OAuth access token with its refresh token and expiry
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub token_type: Option<String>,
+    pub scope: Option<String>,
+    /// Seconds since the UNIX epoch
+    pub expires_at: Option<u64>,
+}
+
+impl Token {
+    /// Whether the token expires within [`TOKEN_EXPIRY_MARGIN_SECS`].
+    pub fn is_expiring(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= unix_timestamp() + TOKEN_EXPIRY_MARGIN_SECS)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    token_type: Option<String>,
+    scope: Option<String>,
+    expires_in: Option<u64>,
+}
+
+impl From<TokenResponse> for Token {
+    fn from(resp: TokenResponse) -> Self {
+        Self {
+            access_token: resp.access_token,
+            refresh_token: resp.refresh_token,
+            token_type: resp.token_type,
+            scope: resp.scope,
+            expires_at: resp.expires_in.map(|secs| unix_timestamp() + secs),
+        }
+    }
+}
+
+/// PKCE code verifier and its S256 challenge
+#[derive(Debug, Clone)]
+pub struct PkceChallenge {
+    pub verifier: String,
+    pub challenge: String,
+}
+
+impl PkceChallenge {
+    /// Generate a new random verifier.
+    pub fn new() -> Self {
+        Self::from_verifier(random_string(64))
+    }
+
+    pub fn from_verifier(verifier: impl Into<String>) -> Self {
+        let verifier = verifier.into();
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+}
+
+impl Default for PkceChallenge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn random_string(len: usize) -> String {
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
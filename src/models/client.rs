@@ -1,10 +1,18 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
-use tokio::sync::RwLock;
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
 
-use crate::models::config::RetryConfig;
+use crate::models::archive::DownloadArchive;
+use crate::models::auth::{OAuthConfig, Token};
+use crate::models::config::{ArchiveConfig, RetryConfig};
+use crate::models::ids::UserId;
+use crate::models::playlist_file::PlaylistFormat;
+use crate::models::sidecar::SidecarFormat;
+use crate::models::token_store::TokenStore;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Identifier {
     Id(i64),
@@ -20,9 +28,36 @@ impl fmt::Display for Identifier {
     }
 }
 
+/// Body of a request sent with [`Client::send`]
+#[derive(Debug, Clone)]
+pub enum RequestBody {
+    Empty,
+    Json(serde_json::Value),
+    Form(Vec<(String, String)>),
+}
+
 /// SoundCloud API client
 #[derive(Debug)]
 pub struct Client {
+    /// HTTP connection pool, shared with every registered account
+    pub http: reqwest::Client,
     pub client_id: RwLock<String>,
+    pub api_url: String,
+    pub public_api_url: String,
     pub retry_config: RetryConfig,
+    pub oauth_config: Option<OAuthConfig>,
+    pub token: Mutex<Option<Token>>,
+    pub token_store: Option<Arc<dyn TokenStore>>,
+    pub archive_config: Option<ArchiveConfig>,
+    pub download_archive: Option<Arc<Mutex<DownloadArchive>>>,
+    pub sidecar_format: Option<SidecarFormat>,
+    pub playlist_formats: Vec<PlaylistFormat>,
+    /// Named accounts added with [`Client::add_account`]
+    pub accounts: std::sync::RwLock<HashMap<String, Arc<Client>>>,
+    /// Drop mistyped fields with a warning instead of failing the response,
+    /// see [`crate::response::FieldWarning`]
+    pub lenient: bool,
+    /// Id of the authenticated user, looked up once for the `get_me_*` methods and
+    /// forgotten when the token is replaced
+    pub me_id: Mutex<Option<UserId>>,
 }
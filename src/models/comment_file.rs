@@ -0,0 +1,222 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::response::{Comment, Track};
+
+/// Timed text formats that comments can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentFileFormat {
+    Srt,
+    WebVtt,
+    Lrc,
+}
+
+impl CommentFileFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CommentFileFormat::Srt => "srt",
+            CommentFileFormat::WebVtt => "vtt",
+            CommentFileFormat::Lrc => "lrc",
+        }
+    }
+
+    /// Path next to the audio file at `audio_path`, e.g. `song.mp3` -> `song.srt`.
+    pub fn path_for(&self, audio_path: &Path) -> PathBuf {
+        audio_path.with_extension(self.extension())
+    }
+
+    /// Render the comments of `track` aligned to its audio.
+    pub fn render(
+        &self,
+        track: &Track,
+        comments: &[Comment],
+        options: &CommentExportOptions,
+    ) -> String {
+        let cues = CommentCue::from_comments(comments, options, track.duration);
+        match self {
+            CommentFileFormat::Srt => render_srt(&cues),
+            CommentFileFormat::WebVtt => render_vtt(&cues),
+            CommentFileFormat::Lrc => render_lrc(track, &cues),
+        }
+    }
+}
+
+/// How comments are turned into cues
+#[derive(Debug, Clone)]
+pub struct CommentExportOptions {
+    /// How long each comment stays on screen, in milliseconds
+    pub display_ms: i64,
+    /// Combine comments whose display times overlap into a single cue
+    pub merge_overlapping: bool,
+    /// Keep at most this many lines per cue, the last kept line notes how many were dropped
+    pub max_lines: Option<usize>,
+    /// Prefix each line with the commenter's username
+    pub include_author: bool,
+}
+
+impl Default for CommentExportOptions {
+    fn default() -> Self {
+        Self {
+            display_ms: 4000,
+            merge_overlapping: false,
+            max_lines: None,
+            include_author: true,
+        }
+    }
+}
+
+/// Text shown between two playback positions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentCue {
+    /// Milliseconds
+    pub start: i64,
+    /// Milliseconds
+    pub end: i64,
+    pub lines: Vec<String>,
+}
+
+impl CommentCue {
+    /// Cues for all comments with a timestamp, ordered by playback position.
+    /// Cues end at `duration` (ms) when it is known.
+    pub fn from_comments(
+        comments: &[Comment],
+        options: &CommentExportOptions,
+        duration: Option<i64>,
+    ) -> Vec<CommentCue> {
+        let mut timed: Vec<(i64, String)> = comments
+            .iter()
+            .filter_map(|c| Some((c.timestamp?, comment_line(c, options)?)))
+            .collect();
+        timed.sort_by_key(|(timestamp, _)| *timestamp);
+
+        let mut cues: Vec<CommentCue> = Vec::new();
+        for (start, line) in timed {
+            let mut end = start + options.display_ms.max(1);
+            if let Some(duration) = duration
+                && duration > start
+            {
+                end = end.min(duration);
+            }
+            if options.merge_overlapping
+                && let Some(last) = cues.last_mut()
+                && start < last.end
+            {
+                last.end = last.end.max(end);
+                last.lines.push(line);
+                continue;
+            }
+            cues.push(CommentCue {
+                start,
+                end,
+                lines: vec![line],
+            });
+        }
+
+        if let Some(max_lines) = options.max_lines.filter(|max| *max > 0) {
+            for cue in &mut cues {
+                if cue.lines.len() > max_lines {
+                    let dropped = cue.lines.len() - max_lines;
+                    cue.lines.truncate(max_lines);
+                    if let Some(last) = cue.lines.last_mut() {
+                        let _ = write!(last, " (+{dropped} more)");
+                    }
+                }
+            }
+        }
+        cues
+    }
+}
+
+fn comment_line(comment: &Comment, options: &CommentExportOptions) -> Option<String> {
+    let body = comment
+        .body
+        .as_deref()?
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if body.is_empty() {
+        return None;
+    }
+    let author = comment.user.as_ref().and_then(|u| u.username.as_deref());
+    match author {
+        Some(author) if options.include_author => Some(format!("{author}: {body}")),
+        _ => Some(body),
+    }
+}
+
+fn render_srt(cues: &[CommentCue]) -> String {
+    let mut out = String::new();
+    for (i, cue) in cues.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{}\n{} --> {}",
+            i + 1,
+            clock(cue.start, ','),
+            clock(cue.end, ',')
+        );
+        for line in &cue.lines {
+            let _ = writeln!(out, "{line}");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_vtt(cues: &[CommentCue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        let _ = writeln!(out, "{} --> {}", clock(cue.start, '.'), clock(cue.end, '.'));
+        for line in &cue.lines {
+            let _ = writeln!(out, "{}", escape_vtt(line));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_lrc(track: &Track, cues: &[CommentCue]) -> String {
+    let mut out = String::new();
+    if let Some(title) = track.title.as_deref() {
+        let _ = writeln!(out, "[ti:{title}]");
+    }
+    if let Some(artist) = track.user.as_ref().and_then(|u| u.username.as_deref()) {
+        let _ = writeln!(out, "[ar:{artist}]");
+    }
+    if let Some(duration) = track.duration {
+        let _ = writeln!(out, "[length:{}]", lrc_clock(duration));
+    }
+    // LRC has one line per timestamp
+    for cue in cues {
+        let _ = writeln!(out, "[{}]{}", lrc_clock(cue.start), cue.lines.join(" / "));
+    }
+    out
+}
+
+/// `HH:MM:SS,mmm` (SRT) or `HH:MM:SS.mmm` (WebVTT)
+fn clock(ms: i64, separator: char) -> String {
+    let ms = ms.max(0);
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+/// `MM:SS.xx`, minutes are not wrapped at an hour
+fn lrc_clock(ms: i64) -> String {
+    let ms = ms.max(0);
+    format!(
+        "{:02}:{:02}.{:02}",
+        ms / 60_000,
+        ms / 1000 % 60,
+        ms % 1000 / 10
+    )
+}
+
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 /// Configuration for client retry behavior
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
@@ -14,3 +16,9 @@ impl Default for RetryConfig {
     }
 }
 
+/// Configuration for the persistent download archive
+#[derive(Debug, Clone)]
+pub struct ArchiveConfig {
+    pub path: PathBuf,
+    pub redownload_on_upgrade: bool,
+}
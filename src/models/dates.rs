@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+#[cfg(feature = "time")]
+use time::{format_description::BorrowedFormatItem, macros::format_description};
+
+use crate::response::{
+    Comment, DateOfBirth, Like, Playlist, Repost, Track, Transcoding, User, WebProfile,
+};
+
+/// A datetime type that SoundCloud timestamps can be parsed into.
+///
+/// Implemented for `chrono::DateTime<Utc>` and `chrono::DateTime<FixedOffset>` with the
+/// `chrono` feature, and for `time::OffsetDateTime` with the `time` feature.
+pub trait Timestamp: Sized {
+    /// Parse `2024-01-31T12:00:00Z` or the older `2024/01/31 12:00:00 +0000` format.
+    fn parse_timestamp(value: &str) -> Option<Self>;
+}
+
+/// A calendar date type, see [`Timestamp`] for the implementing types.
+pub trait CalendarDate: Sized {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self>;
+}
+
+/// `2024/01/31 12:00:00 +0000`, the timestamp format of older responses
+#[cfg(feature = "chrono")]
+const LEGACY_CHRONO_FORMAT: &str = "%Y/%m/%d %H:%M:%S %z";
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::DateTime<chrono::FixedOffset> {
+    fn parse_timestamp(value: &str) -> Option<Self> {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .or_else(|_| chrono::DateTime::parse_from_str(value, LEGACY_CHRONO_FORMAT))
+            .ok()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::DateTime<chrono::Utc> {
+    fn parse_timestamp(value: &str) -> Option<Self> {
+        chrono::DateTime::<chrono::FixedOffset>::parse_timestamp(value)
+            .map(|datetime| datetime.with_timezone(&chrono::Utc))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl CalendarDate for chrono::NaiveDate {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+    }
+}
+
+/// `2024/01/31 12:00:00 +0000`, the timestamp format of older responses
+#[cfg(feature = "time")]
+const LEGACY_TIME_FORMAT: &[BorrowedFormatItem] = format_description!(
+    "[year]/[month]/[day] [hour]:[minute]:[second] [offset_hour sign:mandatory][offset_minute]"
+);
+
+#[cfg(feature = "time")]
+impl Timestamp for time::OffsetDateTime {
+    fn parse_timestamp(value: &str) -> Option<Self> {
+        time::OffsetDateTime::parse(value, &time::format_description::well_known::Rfc3339)
+            .or_else(|_| time::OffsetDateTime::parse(value, LEGACY_TIME_FORMAT))
+            .ok()
+    }
+}
+
+#[cfg(feature = "time")]
+impl CalendarDate for time::Date {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        let month = time::Month::try_from(u8::try_from(month).ok()?).ok()?;
+        time::Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+    }
+}
+
+fn parse<T: Timestamp>(value: Option<&str>) -> Option<T> {
+    T::parse_timestamp(value?)
+}
+
+/// Combine separate year, month and day fields, `None` if any is missing or zero.
+fn date<D: CalendarDate>(year: Option<i32>, month: Option<i32>, day: Option<i32>) -> Option<D> {
+    let (year, month, day) = (year?, month?, day?);
+    if year <= 0 || month <= 0 || day <= 0 {
+        return None;
+    }
+    D::from_ymd(year, month as u32, day as u32)
+}
+
+/// A date from the `YYYY-MM-DD` prefix of `value`, which may be a full timestamp.
+fn date_prefix<D: CalendarDate>(value: Option<&str>) -> Option<D> {
+    let mut parts = value?.get(..10)?.split('-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    date(Some(year), Some(month), Some(day))
+}
+
+fn millis(duration: Option<i64>) -> Option<Duration> {
+    u64::try_from(duration?).ok().map(Duration::from_millis)
+}
+
+macro_rules! created_at {
+    ($($model:ty),*) => {
+        $(
+            impl $model {
+                /// `created_at` parsed into a datetime, e.g. `created_at_as::<DateTime<Utc>>()`.
+                pub fn created_at_as<T: Timestamp>(&self) -> Option<T> {
+                    parse(self.created_at.as_deref())
+                }
+            }
+        )*
+    };
+}
+
+created_at!(Track, Playlist, User, Comment, Like, Repost, WebProfile);
+
+impl Track {
+    pub fn last_modified_as<T: Timestamp>(&self) -> Option<T> {
+        parse(self.last_modified.as_deref())
+    }
+
+    /// `release_year`, `release_month` and `release_day` as a date, or `release` when it
+    /// holds a `YYYY-MM-DD` date and the separate fields are missing.
+    pub fn release_date<D: CalendarDate>(&self) -> Option<D> {
+        date(self.release_year, self.release_month, self.release_day)
+            .or_else(|| date_prefix(self.release.as_deref()))
+    }
+
+    /// `duration` as a [`Duration`] instead of milliseconds.
+    pub fn playback_duration(&self) -> Option<Duration> {
+        millis(self.duration)
+    }
+}
+
+impl Playlist {
+    pub fn last_modified_as<T: Timestamp>(&self) -> Option<T> {
+        parse(self.last_modified.as_deref())
+    }
+
+    /// `release_year`, `release_month` and `release_day` as a date, or `release` when it
+    /// holds a `YYYY-MM-DD` date and the separate fields are missing.
+    pub fn release_date<D: CalendarDate>(&self) -> Option<D> {
+        date(self.release_year, self.release_month, self.release_day)
+            .or_else(|| date_prefix(self.release.as_deref()))
+    }
+
+    /// Total `duration` of the tracks as a [`Duration`].
+    pub fn playback_duration(&self) -> Option<Duration> {
+        millis(self.duration)
+    }
+}
+
+impl User {
+    pub fn last_modified_as<T: Timestamp>(&self) -> Option<T> {
+        parse(self.last_modified.as_deref())
+    }
+}
+
+impl DateOfBirth {
+    pub fn date<D: CalendarDate>(&self) -> Option<D> {
+        date(
+            self.year.map(i32::from),
+            self.month.map(i32::from),
+            self.day.map(i32::from),
+        )
+    }
+}
+
+impl Transcoding {
+    pub fn playback_duration(&self) -> Option<Duration> {
+        millis(self.duration)
+    }
+}
@@ -0,0 +1,173 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Defines an enum over the string values of an API field. Anything without a variant is
+/// kept unchanged in `Unknown`, so responses keep parsing when SoundCloud adds a value and
+/// serialize back to what was received.
+macro_rules! string_enum {
+    (
+        $(#[$doc:meta])*
+        $name:ident { $($(#[$variant_doc:meta])* $variant:ident => $value:literal),* $(,)? }
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_doc])* $variant,)*
+            /// A value without a variant, as sent by the API
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::from(value.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Infallible> {
+                Ok(Self::from(value))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+string_enum!(
+    /// Who can see a track or playlist
+    Sharing {
+        Public => "public",
+        Private => "private",
+    }
+);
+
+string_enum!(
+    /// How much of a track can be streamed in the current region and account
+    Access {
+        Playable => "playable",
+        /// Only a 30 second snippet
+        Preview => "preview",
+        Blocked => "blocked",
+    }
+);
+
+string_enum!(
+    /// Who can embed a track or playlist on other sites
+    EmbeddableBy {
+        All => "all",
+        Me => "me",
+        Nobody => "none",
+    }
+);
+
+string_enum!(
+    /// Copyright license of a track or playlist
+    License {
+        AllRightsReserved => "all-rights-reserved",
+        NoRightsReserved => "no-rights-reserved",
+        CcBy => "cc-by",
+        CcBySa => "cc-by-sa",
+        CcByNd => "cc-by-nd",
+        CcByNc => "cc-by-nc",
+        CcByNcSa => "cc-by-nc-sa",
+        CcByNcNd => "cc-by-nc-nd",
+    }
+);
+
+string_enum!(
+    /// The `kind` of an API resource
+    ResourceKind {
+        Track => "track",
+        User => "user",
+        Playlist => "playlist",
+        SystemPlaylist => "system-playlist",
+        Comment => "comment",
+        Like => "like",
+        Selection => "selection",
+        Station => "station",
+        /// An autocomplete suggestion to search for
+        Query => "query",
+    }
+);
+
+string_enum!(
+    /// `playlist_type` and `set_type` of a playlist or album
+    PlaylistType {
+        Album => "album",
+        Ep => "ep",
+        Single => "single",
+        EpSingle => "ep single",
+        Compilation => "compilation",
+        Archive => "archive",
+        Showcase => "showcase",
+        Demo => "demo",
+        SamplePack => "sample pack",
+        Other => "other",
+    }
+);
+
+/// Serde helpers for a list of [`Access`] values sent as `playable,preview`.
+pub(crate) mod access_list {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Access;
+
+    pub fn serialize<S: Serializer>(
+        access: &Option<Vec<Access>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match access {
+            Some(access) => {
+                let values: Vec<&str> = access.iter().map(Access::as_str).collect();
+                serializer.serialize_some(&values.join(","))
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<Access>>, D::Error> {
+        let value = Option::<String>::deserialize(deserializer)?;
+        Ok(value.map(|value| value.split(',').map(|v| Access::from(v.trim())).collect()))
+    }
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::path::{Path, PathBuf};
+
+use crate::models::error::Error;
+use crate::models::ids::{PlaylistId, TrackId, UserId};
+use crate::response::{Playlist, Repost, StreamType, Track, User};
+
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+pub const EXPORT_INDEX_FILE_NAME: &str = "archive.json";
+
+/// What to include in an artist archive export
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    pub include_audio: bool,
+    pub include_artwork: bool,
+    pub include_reposts: bool,
+    pub include_followers: bool,
+    pub include_followings: bool,
+    pub stream_type: Option<StreamType>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            include_audio: true,
+            include_artwork: true,
+            include_reposts: true,
+            include_followers: true,
+            include_followings: true,
+            stream_type: None,
+        }
+    }
+}
+
+/// Top level `archive.json` describing the layout of an export directory.
+/// All paths are relative to the export directory.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ExportIndex {
+    pub format_version: u32,
+    pub exported_at: u64,
+    pub user_id: Option<UserId>,
+    pub profile: PathBuf,
+    pub avatar: Option<PathBuf>,
+    pub visuals: Vec<PathBuf>,
+    /// Exported tracks in the order returned by the API
+    pub tracks: Vec<ExportedTrack>,
+    /// Exported playlists in the order returned by the API
+    pub playlists: Vec<ExportedPlaylist>,
+    pub reposts: Option<PathBuf>,
+    pub followers: Option<PathBuf>,
+    pub followings: Option<PathBuf>,
+    /// Items that could not be exported, with the reason
+    pub failures: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ExportedTrack {
+    pub id: TrackId,
+    pub metadata: PathBuf,
+    pub audio: Option<PathBuf>,
+    pub artwork: Option<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ExportedPlaylist {
+    pub id: PlaylistId,
+    pub metadata: PathBuf,
+    pub artwork: Option<PathBuf>,
+}
+
+/// An artist archive read back from disk
+#[derive(Debug, Default, Clone)]
+pub struct ArtistArchive {
+    pub index: ExportIndex,
+    pub user: User,
+    pub tracks: Vec<Track>,
+    pub playlists: Vec<Playlist>,
+    pub reposts: Vec<Repost>,
+    pub followers: Vec<User>,
+    pub followings: Vec<User>,
+}
+
+impl ArtistArchive {
+    /// Read an export directory back into the crate's model types.
+    pub fn load(directory: impl AsRef<Path>) -> Result<Self, Error> {
+        let directory = directory.as_ref();
+        let index: ExportIndex = read_json(&directory.join(EXPORT_INDEX_FILE_NAME))?;
+        if index.format_version > EXPORT_FORMAT_VERSION {
+            return Err(Error::new(format!(
+                "Unsupported archive format version {}",
+                index.format_version
+            )));
+        }
+
+        let user = read_json(&directory.join(&index.profile))?;
+        let tracks = index
+            .tracks
+            .iter()
+            .map(|t| read_json(&directory.join(&t.metadata)))
+            .collect::<Result<_, _>>()?;
+        let playlists = index
+            .playlists
+            .iter()
+            .map(|p| read_json(&directory.join(&p.metadata)))
+            .collect::<Result<_, _>>()?;
+        let reposts = read_optional(directory, &index.reposts)?;
+        let followers = read_optional(directory, &index.followers)?;
+        let followings = read_optional(directory, &index.followings)?;
+
+        Ok(Self {
+            index,
+            user,
+            tracks,
+            playlists,
+            reposts,
+            followers,
+            followings,
+        })
+    }
+}
+
+fn read_optional<T: DeserializeOwned>(
+    directory: &Path,
+    path: &Option<PathBuf>,
+) -> Result<Vec<T>, Error> {
+    match path {
+        Some(path) => read_json(&directory.join(path)),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub(crate) fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, Error> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| Error::from_error(format!("Failed to read {}", path.display()), e))?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+pub(crate) fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_vec_pretty(value)?)?;
+    Ok(())
+}
@@ -0,0 +1,292 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::models::client::Identifier;
+use crate::models::error::Error;
+use crate::response::{Playlist, Track, User};
+
+/// Something that identifies an entity of type `E`.
+///
+/// Implemented by the typed ids, so passing a [`UserId`] where a track is expected does not
+/// compile. An untyped [`Identifier`] has to be wrapped in [`Unchecked`] first.
+pub trait IdentifierFor<E> {
+    fn into_identifier(self) -> Identifier;
+}
+
+/// Escape hatch for passing an untyped [`Identifier`] where a typed id is expected, e.g. an
+/// id read from user input whose entity is only known at runtime.
+///
+/// The entity is not checked at compile time. A URN of the wrong kind is still rejected
+/// by the methods that need a numeric id, but otherwise goes to the API as is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Unchecked(pub Identifier);
+
+impl<E> IdentifierFor<E> for Unchecked {
+    fn into_identifier(self) -> Identifier {
+        self.0
+    }
+}
+
+impl<E> IdentifierFor<E> for &Unchecked {
+    fn into_identifier(self) -> Identifier {
+        self.0.clone()
+    }
+}
+
+macro_rules! entity_id {
+    ($(#[$doc:meta])* $name:ident, $entity:ty, $urn_kind:literal) => {
+        $(#[$doc])*
+        #[derive(
+            Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub i64);
+
+        impl $name {
+            /// Kind segment of URNs for this entity, e.g. `tracks` in `soundcloud:tracks:123`
+            pub const URN_KIND: &'static str = $urn_kind;
+
+            pub fn urn(&self) -> Urn {
+                Urn::new($urn_kind, self.0.to_string())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl From<i64> for $name {
+            fn from(id: i64) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for Identifier {
+            fn from(id: $name) -> Self {
+                Identifier::Id(id.0)
+            }
+        }
+
+        impl TryFrom<&Urn> for $name {
+            type Error = Error;
+
+            fn try_from(urn: &Urn) -> Result<Self, Error> {
+                if urn.kind != $urn_kind {
+                    return Err(Error::new(format!(
+                        "Expected a {} URN, got {urn}",
+                        $urn_kind
+                    )));
+                }
+                urn.numeric_id().map(Self)
+            }
+        }
+
+        impl TryFrom<&Identifier> for $name {
+            type Error = Error;
+
+            fn try_from(identifier: &Identifier) -> Result<Self, Error> {
+                identifier.numeric_id($urn_kind).map(Self)
+            }
+        }
+
+        impl IdentifierFor<$entity> for $name {
+            fn into_identifier(self) -> Identifier {
+                self.into()
+            }
+        }
+
+        impl IdentifierFor<$entity> for &$name {
+            fn into_identifier(self) -> Identifier {
+                (*self).into()
+            }
+        }
+    };
+}
+
+entity_id!(
+    /// Id of a [`Track`]
+    TrackId,
+    Track,
+    "tracks"
+);
+entity_id!(
+    /// Id of a [`User`]
+    UserId,
+    User,
+    "users"
+);
+entity_id!(
+    /// Id of a [`Playlist`] or album
+    PlaylistId,
+    Playlist,
+    "playlists"
+);
+
+/// A parsed SoundCloud URN such as `soundcloud:tracks:123`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Urn {
+    /// e.g. `tracks`, `users`, `playlists` or `system-playlists`
+    pub kind: String,
+    pub id: String,
+}
+
+impl Urn {
+    pub fn new(kind: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            id: id.into(),
+        }
+    }
+
+    /// The id as a number, for the entities that use numeric ids.
+    pub fn numeric_id(&self) -> Result<i64, Error> {
+        self.id
+            .parse()
+            .map_err(|e| Error::from_error(format!("URN {self} has no numeric id"), e))
+    }
+}
+
+impl fmt::Display for Urn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "soundcloud:{}:{}", self.kind, self.id)
+    }
+}
+
+impl FromStr for Urn {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut parts = s.splitn(3, ':');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("soundcloud"), Some(kind), Some(id)) if !kind.is_empty() && !id.is_empty() => {
+                Ok(Urn::new(kind, id))
+            }
+            _ => Err(Error::new(format!("Invalid SoundCloud URN: {s}"))),
+        }
+    }
+}
+
+impl Serialize for Urn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Urn {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Urn> for Identifier {
+    fn from(urn: Urn) -> Self {
+        Identifier::Urn(urn.to_string())
+    }
+}
+
+/// URN of a station, e.g. `soundcloud:track-stations:123` or `soundcloud:artist-stations:7`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StationUrn(Urn);
+
+impl StationUrn {
+    pub fn urn(&self) -> &Urn {
+        &self.0
+    }
+}
+
+impl TryFrom<Urn> for StationUrn {
+    type Error = Error;
+
+    fn try_from(urn: Urn) -> Result<Self, Error> {
+        if !urn.kind.ends_with("-stations") {
+            return Err(Error::new(format!("Expected a station URN, got {urn}")));
+        }
+        Ok(Self(urn))
+    }
+}
+
+impl FromStr for StationUrn {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        s.parse::<Urn>()?.try_into()
+    }
+}
+
+impl fmt::Display for StationUrn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for StationUrn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StationUrn {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Urn::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Identifier {
+    /// Wrap for a method taking a typed id, see [`Unchecked`].
+    pub fn unchecked(self) -> Unchecked {
+        Unchecked(self)
+    }
+
+    /// The numeric id, parsed from the URN if needed. Fails for a URN whose kind is not
+    /// `urn_kind`, e.g. a `soundcloud:tracks:5` URN when a user is expected.
+    pub fn numeric_id(&self, urn_kind: &str) -> Result<i64, Error> {
+        match self {
+            Identifier::Id(id) => Ok(*id),
+            Identifier::Urn(urn) => {
+                let urn: Urn = urn.parse()?;
+                if urn.kind != urn_kind {
+                    return Err(Error::new(format!("Expected a {urn_kind} URN, got {urn}")));
+                }
+                urn.numeric_id()
+            }
+        }
+    }
+}
+
+impl Track {
+    /// Typed id, taken from `id` or parsed from `urn`.
+    pub fn track_id(&self) -> Option<TrackId> {
+        typed_id(self.id, self.urn.as_deref())
+    }
+}
+
+impl User {
+    /// Typed id, taken from `id` or parsed from `urn`.
+    pub fn user_id(&self) -> Option<UserId> {
+        typed_id(self.id, self.urn.as_deref())
+    }
+}
+
+impl Playlist {
+    /// Typed id, taken from `id` or parsed from `urn`.
+    pub fn playlist_id(&self) -> Option<PlaylistId> {
+        typed_id(self.id, self.urn.as_deref())
+    }
+}
+
+fn typed_id<T>(id: Option<T>, urn: Option<&str>) -> Option<T>
+where
+    T: for<'a> TryFrom<&'a Urn>,
+{
+    if id.is_some() {
+        return id;
+    }
+    let urn: Urn = urn?.parse().ok()?;
+    T::try_from(&urn).ok()
+}
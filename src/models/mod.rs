@@ -1,11 +1,37 @@
+pub mod account;
+pub mod archive;
+pub mod auth;
 pub mod client;
+pub mod comment_file;
 pub mod config;
+pub mod dates;
+pub mod enums;
 pub mod error;
+pub mod export;
+pub mod ids;
+pub mod playlist_file;
 pub mod query;
 pub mod response;
+pub mod sidecar;
+pub mod sync;
+pub mod token_store;
+pub mod upload;
 
+pub use account::*;
+pub use archive::*;
+pub use auth::*;
 pub use client::*;
+pub use comment_file::*;
 pub use config::*;
+pub use dates::*;
+pub use enums::*;
 pub use error::*;   
+pub use export::*;
+pub use ids::*;
+pub use playlist_file::*;
 pub use query::*;
-pub use response::*;
\ No newline at end of file
+pub use response::*;
+pub use sidecar::*;
+pub use sync::*;
+pub use token_store::*;
+pub use upload::*;
\ No newline at end of file
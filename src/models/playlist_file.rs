@@ -0,0 +1,195 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::models::error::Error;
+
+use crate::response::{Playlist, Track};
+
+/// Playlist file formats that can be generated for a [`Playlist`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistFormat {
+    M3u8,
+    Xspf,
+    Pls,
+}
+
+impl PlaylistFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            PlaylistFormat::M3u8 => "m3u8",
+            PlaylistFormat::Xspf => "xspf",
+            PlaylistFormat::Pls => "pls",
+        }
+    }
+
+    /// Render `entries` as a playlist file, using `playlist` for the header fields.
+    pub fn render(&self, playlist: &Playlist, entries: &[PlaylistEntry]) -> String {
+        match self {
+            PlaylistFormat::M3u8 => render_m3u8(playlist, entries),
+            PlaylistFormat::Xspf => render_xspf(playlist, entries),
+            PlaylistFormat::Pls => render_pls(entries),
+        }
+    }
+
+    /// Render a playlist whose entries point at each track's `permalink_url`.
+    pub fn render_remote(&self, playlist: &Playlist) -> String {
+        let entries: Vec<PlaylistEntry> = playlist
+            .tracks
+            .iter()
+            .flatten()
+            .filter_map(PlaylistEntry::remote)
+            .collect();
+        self.render(playlist, &entries)
+    }
+}
+
+/// A single line of a playlist file
+#[derive(Debug, Clone, Default)]
+pub struct PlaylistEntry {
+    /// File path or URL of the audio
+    pub location: String,
+    pub title: Option<String>,
+    pub creator: Option<String>,
+    /// Duration in milliseconds
+    pub duration: Option<i64>,
+    pub artwork_url: Option<String>,
+}
+
+impl PlaylistEntry {
+    pub fn from_track(track: &Track, location: impl Into<String>) -> Self {
+        Self {
+            location: location.into(),
+            title: track.title.clone(),
+            creator: track.user.as_ref().and_then(|u| u.username.clone()),
+            duration: track.duration,
+            artwork_url: track.artwork_url.clone(),
+        }
+    }
+
+    /// Entry pointing at the track's `permalink_url`, if it has one.
+    pub fn remote(track: &Track) -> Option<Self> {
+        let url = track.permalink_url.as_ref()?;
+        Some(Self::from_track(track, url.clone()))
+    }
+
+    fn display_title(&self) -> String {
+        match (&self.creator, &self.title) {
+            (Some(creator), Some(title)) => format!("{creator} - {title}"),
+            (None, Some(title)) => title.clone(),
+            _ => self.location.clone(),
+        }
+    }
+
+    fn duration_secs(&self) -> i64 {
+        self.duration.map(|ms| ms / 1000).unwrap_or(-1)
+    }
+}
+
+/// `path` relative to the playlist `directory`, stepping up with `..` when the audio lives
+/// elsewhere, e.g. a track the download archive skipped. Paths on another root, such as
+/// another drive on Windows, stay absolute.
+pub(crate) fn relative_location(path: &Path, directory: &Path) -> Result<PathBuf, Error> {
+    let path = std::path::absolute(path)?;
+    let directory = std::path::absolute(directory)?;
+    let common = path
+        .components()
+        .zip(directory.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 {
+        return Ok(path);
+    }
+    let mut relative = PathBuf::new();
+    for _ in directory.components().skip(common) {
+        relative.push("..");
+    }
+    relative.extend(path.components().skip(common));
+    Ok(relative)
+}
+
+fn render_m3u8(playlist: &Playlist, entries: &[PlaylistEntry]) -> String {
+    let mut out = String::from("#EXTM3U\n");
+    if let Some(title) = &playlist.title {
+        let _ = writeln!(out, "#PLAYLIST:{title}");
+    }
+    for entry in entries {
+        let _ = writeln!(out, "#EXTINF:{},{}", entry.duration_secs(), entry.display_title());
+        let _ = writeln!(out, "{}", entry.location);
+    }
+    out
+}
+
+fn render_pls(entries: &[PlaylistEntry]) -> String {
+    let mut out = String::from("[playlist]\n");
+    for (i, entry) in entries.iter().enumerate() {
+        let n = i + 1;
+        let _ = writeln!(out, "File{n}={}", entry.location);
+        let _ = writeln!(out, "Title{n}={}", entry.display_title());
+        let _ = writeln!(out, "Length{n}={}", entry.duration_secs());
+    }
+    let _ = writeln!(out, "NumberOfEntries={}", entries.len());
+    out.push_str("Version=2\n");
+    out
+}
+
+fn render_xspf(playlist: &Playlist, entries: &[PlaylistEntry]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<playlist version=\"1\" xmlns=\"http://xspf.org/ns/0/\">\n");
+    if let Some(title) = &playlist.title {
+        let _ = writeln!(out, "  <title>{}</title>", xml_escape(title));
+    }
+    if let Some(creator) = playlist.user.as_ref().and_then(|u| u.username.as_ref()) {
+        let _ = writeln!(out, "  <creator>{}</creator>", xml_escape(creator));
+    }
+    if let Some(url) = &playlist.permalink_url {
+        let _ = writeln!(out, "  <info>{}</info>", xml_escape(url));
+    }
+    out.push_str("  <trackList>\n");
+    for entry in entries {
+        out.push_str("    <track>\n");
+        let location = xml_escape(&uri_encode(&entry.location));
+        let _ = writeln!(out, "      <location>{location}</location>");
+        if let Some(title) = &entry.title {
+            let _ = writeln!(out, "      <title>{}</title>", xml_escape(title));
+        }
+        if let Some(creator) = &entry.creator {
+            let _ = writeln!(out, "      <creator>{}</creator>", xml_escape(creator));
+        }
+        if let Some(duration) = entry.duration {
+            let _ = writeln!(out, "      <duration>{duration}</duration>");
+        }
+        if let Some(artwork_url) = &entry.artwork_url {
+            let _ = writeln!(out, "      <image>{}</image>", xml_escape(artwork_url));
+        }
+        out.push_str("    </track>\n");
+    }
+    out.push_str("  </trackList>\n</playlist>\n");
+    out
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// XSPF locations are URIs, so local paths need percent-encoding. URLs are kept as-is.
+fn uri_encode(location: &str) -> String {
+    if location.contains("://") {
+        return location.to_string();
+    }
+    let mut out = String::with_capacity(location.len());
+    for byte in location.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                out.push(byte as char)
+            }
+            _ => {
+                let _ = write!(out, "%{byte:02X}");
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ChartsQuery {
+    /// `top` or `trending`
+    pub kind: Option<String>,
+    /// Genre URN, e.g. `soundcloud:genres:all-music` or `soundcloud:genres:techno`
+    pub genre: Option<String>,
+    /// Region URN, e.g. `soundcloud:regions:US`
+    pub region: Option<String>,
+    pub high_tier_only: Option<bool>,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+    pub linked_partitioning: Option<bool>,
+}
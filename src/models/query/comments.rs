@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct CommentsQuery {
+    /// `newest` or `timestamp` (by playback position)
+    pub sort: Option<String>,
+    /// Group replies with the comment they answer
+    pub threaded: Option<bool>,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+    pub linked_partitioning: Option<bool>,
+}
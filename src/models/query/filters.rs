@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+/// Typed `filter.*` parameters for track searches, see [`crate::query::TracksQuery::filters`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TrackFilters {
+    pub bpm: Option<ValueRange<u32>>,
+    pub duration: Option<DurationFilter>,
+    pub created_at: Option<CreatedAtFilter>,
+    pub license: Option<LicenseFilter>,
+    pub genres: Vec<String>,
+    pub tags: Vec<String>,
+    pub sort: Option<SearchSort>,
+    /// Return counts for this facet, e.g. `genre`, in [`crate::response::PagingCollection::facets`]
+    pub facet: Option<String>,
+}
+
+/// Inclusive range, either end may be left open
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ValueRange<T> {
+    pub from: Option<T>,
+    pub to: Option<T>,
+}
+
+impl<T> ValueRange<T> {
+    pub fn between(from: T, to: T) -> Self {
+        Self {
+            from: Some(from),
+            to: Some(to),
+        }
+    }
+
+    pub fn at_least(from: T) -> Self {
+        Self {
+            from: Some(from),
+            to: None,
+        }
+    }
+
+    pub fn at_most(to: T) -> Self {
+        Self {
+            from: None,
+            to: Some(to),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationFilter {
+    /// Under 2 minutes
+    Short,
+    /// 2 to 10 minutes
+    Medium,
+    /// 10 to 30 minutes
+    Long,
+    /// Over 30 minutes
+    Epic,
+    Range(ValueRange<Duration>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreatedAtFilter {
+    LastHour,
+    LastDay,
+    LastWeek,
+    LastMonth,
+    LastYear,
+    /// Timestamps as `YYYY-MM-DD HH:MM:SS`
+    Range(ValueRange<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseFilter {
+    ToShare,
+    ToModify,
+    ToUseCommercially,
+    ToModifyCommercially,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchSort {
+    Relevance,
+    Newest,
+    Popular,
+}
+
+impl DurationFilter {
+    /// Wire value of a preset, `None` for [`DurationFilter::Range`]
+    fn preset(&self) -> Option<&'static str> {
+        match self {
+            DurationFilter::Short => Some("short"),
+            DurationFilter::Medium => Some("medium"),
+            DurationFilter::Long => Some("long"),
+            DurationFilter::Epic => Some("epic"),
+            DurationFilter::Range(_) => None,
+        }
+    }
+
+    fn from_preset(value: &str) -> Option<Self> {
+        match value {
+            "short" => Some(DurationFilter::Short),
+            "medium" => Some(DurationFilter::Medium),
+            "long" => Some(DurationFilter::Long),
+            "epic" => Some(DurationFilter::Epic),
+            _ => None,
+        }
+    }
+}
+
+impl CreatedAtFilter {
+    /// Wire value of a preset, `None` for [`CreatedAtFilter::Range`]
+    fn preset(&self) -> Option<&'static str> {
+        match self {
+            CreatedAtFilter::LastHour => Some("last_hour"),
+            CreatedAtFilter::LastDay => Some("last_day"),
+            CreatedAtFilter::LastWeek => Some("last_week"),
+            CreatedAtFilter::LastMonth => Some("last_month"),
+            CreatedAtFilter::LastYear => Some("last_year"),
+            CreatedAtFilter::Range(_) => None,
+        }
+    }
+
+    fn from_preset(value: &str) -> Option<Self> {
+        match value {
+            "last_hour" => Some(CreatedAtFilter::LastHour),
+            "last_day" => Some(CreatedAtFilter::LastDay),
+            "last_week" => Some(CreatedAtFilter::LastWeek),
+            "last_month" => Some(CreatedAtFilter::LastMonth),
+            "last_year" => Some(CreatedAtFilter::LastYear),
+            _ => None,
+        }
+    }
+}
+
+impl LicenseFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LicenseFilter::ToShare => "to_share",
+            LicenseFilter::ToModify => "to_modify",
+            LicenseFilter::ToUseCommercially => "to_use_commercially",
+            LicenseFilter::ToModifyCommercially => "to_modify_commercially",
+        }
+    }
+
+    fn from_wire(value: &str) -> Option<Self> {
+        match value {
+            "to_share" => Some(LicenseFilter::ToShare),
+            "to_modify" => Some(LicenseFilter::ToModify),
+            "to_use_commercially" => Some(LicenseFilter::ToUseCommercially),
+            "to_modify_commercially" => Some(LicenseFilter::ToModifyCommercially),
+            _ => None,
+        }
+    }
+}
+
+impl SearchSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Newest => "newest",
+            SearchSort::Popular => "popular",
+        }
+    }
+
+    fn from_wire(value: &str) -> Option<Self> {
+        match value {
+            "relevance" => Some(SearchSort::Relevance),
+            "newest" => Some(SearchSort::Newest),
+            "popular" => Some(SearchSort::Popular),
+            _ => None,
+        }
+    }
+}
+
+impl TrackFilters {
+    /// Wire format as `(name, value)` pairs, e.g. `("filter.bpm.from", "120")`.
+    pub fn to_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        let mut push = |name: &str, value: String| params.push((name.to_string(), value));
+
+        if let Some(bpm) = &self.bpm {
+            if let Some(from) = bpm.from {
+                push("filter.bpm.from", from.to_string());
+            }
+            if let Some(to) = bpm.to {
+                push("filter.bpm.to", to.to_string());
+            }
+        }
+        match &self.duration {
+            Some(DurationFilter::Range(range)) => {
+                if let Some(from) = range.from {
+                    push("filter.duration.from", from.as_millis().to_string());
+                }
+                if let Some(to) = range.to {
+                    push("filter.duration.to", to.as_millis().to_string());
+                }
+            }
+            Some(preset) => {
+                if let Some(preset) = preset.preset() {
+                    push("filter.duration", preset.to_string());
+                }
+            }
+            None => {}
+        }
+        match &self.created_at {
+            Some(CreatedAtFilter::Range(range)) => {
+                if let Some(from) = &range.from {
+                    push("filter.created_at.from", from.clone());
+                }
+                if let Some(to) = &range.to {
+                    push("filter.created_at.to", to.clone());
+                }
+            }
+            Some(preset) => {
+                if let Some(preset) = preset.preset() {
+                    push("filter.created_at", preset.to_string());
+                }
+            }
+            None => {}
+        }
+        if let Some(license) = self.license {
+            push("filter.license", license.as_str().to_string());
+        }
+        if !self.genres.is_empty() {
+            push("filter.genre", self.genres.join(","));
+        }
+        if !self.tags.is_empty() {
+            push("filter.tag", self.tags.join(","));
+        }
+        if let Some(sort) = self.sort {
+            push("sort", sort.as_str().to_string());
+        }
+        if let Some(facet) = &self.facet {
+            push("facet", facet.clone());
+        }
+        params
+    }
+
+    /// Inverse of [`TrackFilters::to_params`]. Parameters that are not filters are ignored.
+    pub fn from_params<'a>(
+        params: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, String> {
+        fn parse<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("Invalid value for {name}: {value}"))
+        }
+        fn millis(name: &str, value: &str) -> Result<Duration, String> {
+            parse(name, value).map(Duration::from_millis)
+        }
+        fn list(value: &str) -> Vec<String> {
+            value.split(',').map(|v| v.trim().to_string()).collect()
+        }
+        fn preset<T>(name: &str, value: &str, parsed: Option<T>) -> Result<T, String> {
+            parsed.ok_or_else(|| format!("Unknown value for {name}: {value}"))
+        }
+
+        let mut filters = TrackFilters::default();
+        let mut bpm = ValueRange::<u32>::default();
+        let mut duration = ValueRange::<Duration>::default();
+        let mut created_at = ValueRange::<String>::default();
+        for (name, value) in params {
+            match name {
+                "filter.bpm.from" => bpm.from = Some(parse(name, value)?),
+                "filter.bpm.to" => bpm.to = Some(parse(name, value)?),
+                "filter.duration" => {
+                    filters.duration =
+                        Some(preset(name, value, DurationFilter::from_preset(value))?)
+                }
+                "filter.duration.from" => duration.from = Some(millis(name, value)?),
+                "filter.duration.to" => duration.to = Some(millis(name, value)?),
+                "filter.created_at" => {
+                    filters.created_at =
+                        Some(preset(name, value, CreatedAtFilter::from_preset(value))?)
+                }
+                "filter.created_at.from" => created_at.from = Some(value.to_string()),
+                "filter.created_at.to" => created_at.to = Some(value.to_string()),
+                "filter.license" => {
+                    filters.license = Some(preset(name, value, LicenseFilter::from_wire(value))?)
+                }
+                "filter.genre" => filters.genres = list(value),
+                "filter.tag" => filters.tags = list(value),
+                "sort" => filters.sort = Some(preset(name, value, SearchSort::from_wire(value))?),
+                "facet" => filters.facet = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        if bpm != ValueRange::default() {
+            filters.bpm = Some(bpm);
+        }
+        if duration != ValueRange::default() {
+            filters.duration = Some(DurationFilter::Range(duration));
+        }
+        if created_at != ValueRange::default() {
+            filters.created_at = Some(CreatedAtFilter::Range(created_at));
+        }
+        Ok(filters)
+    }
+}
+
+impl Serialize for TrackFilters {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let params = self.to_params();
+        let mut map = serializer.serialize_map(Some(params.len()))?;
+        for (name, value) in &params {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TrackFilters {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let params = BTreeMap::<String, Value>::deserialize(deserializer)?;
+        let params: Vec<(&str, String)> = params
+            .iter()
+            .map(|(name, value)| match value {
+                Value::String(value) => (name.as_str(), value.clone()),
+                value => (name.as_str(), value.to_string()),
+            })
+            .collect();
+        TrackFilters::from_params(params.iter().map(|(name, value)| (*name, value.as_str())))
+            .map_err(de::Error::custom)
+    }
+}
@@ -1,10 +1,16 @@
 mod album;
+mod charts;
+mod comments;
+mod filters;
 mod playlists;
 mod search;
 mod tracks;
 mod users;
 
 pub use album::*;
+pub use charts::*;
+pub use comments::*;
+pub use filters::*;
 pub use playlists::*;
 pub use search::*;
 pub use tracks::*;
@@ -1,11 +1,34 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::enums::{Access, Sharing, access_list};
+use crate::models::ids::TrackId;
+
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct PlaylistsQuery {
     pub q: Option<String>,
-    pub access: Option<String>,
+    /// Sent as a comma separated list, e.g. `playable,preview`
+    #[serde(with = "access_list", default)]
+    pub access: Option<Vec<Access>>,
     pub show_tracks: Option<bool>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
     pub linked_partitioning: Option<bool>,
 }
+
+/// Fields sent when creating or editing a playlist. Fields left as `None` are not changed.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct PlaylistParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing: Option<Sharing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_list: Option<String>,
+    /// Track IDs in playlist order, replaces the whole track list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracks: Option<Vec<TrackId>>,
+}
@@ -15,3 +15,15 @@ pub struct SearchAllQuery {
     pub offset: Option<i32>,
     pub linked_partitioning: Option<bool>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct AutocompleteQuery {
+    pub q: Option<String>,
+    /// Maximum number of suggested queries
+    pub queries_limit: Option<i32>,
+    /// Maximum number of suggested tracks, users and playlists
+    pub results_limit: Option<i32>,
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+    pub linked_partitioning: Option<bool>,
+}
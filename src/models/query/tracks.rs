@@ -1,4 +1,8 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::models::enums::{Access, License, Sharing, access_list};
+use crate::models::error::Error;
+use crate::models::query::filters::TrackFilters;
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct TracksQuery {
@@ -10,10 +14,56 @@ pub struct TracksQuery {
     pub bpm: Option<String>,
     pub duration: Option<String>,
     pub created_at: Option<String>,
-    pub access: Option<String>,
+    /// Sent as a comma separated list, e.g. `playable,preview`
+    #[serde(with = "access_list", default)]
+    pub access: Option<Vec<Access>>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
     pub linked_partitioning: Option<bool>,
+    /// Typed `filter.*` parameters. Cannot be combined with the free-form `genres`, `tags`,
+    /// `bpm`, `duration` and `created_at` fields, which filter on the same values.
+    #[serde(flatten, deserialize_with = "non_empty_filters")]
+    pub filters: Option<TrackFilters>,
+}
+
+impl TracksQuery {
+    /// Fails when `filters` and a free-form field both filter on the same value, as the
+    /// API would receive the filter twice.
+    pub(crate) fn check_filters(&self) -> Result<(), Error> {
+        let Some(filters) = &self.filters else {
+            return Ok(());
+        };
+        let conflicts = [
+            (
+                "genres",
+                self.genres.is_some() && !filters.genres.is_empty(),
+            ),
+            ("tags", self.tags.is_some() && !filters.tags.is_empty()),
+            ("bpm", self.bpm.is_some() && filters.bpm.is_some()),
+            (
+                "duration",
+                self.duration.is_some() && filters.duration.is_some(),
+            ),
+            (
+                "created_at",
+                self.created_at.is_some() && filters.created_at.is_some(),
+            ),
+        ];
+        match conflicts.iter().find(|(_, conflict)| *conflict) {
+            Some((name, _)) => Err(Error::new(format!(
+                "TracksQuery sets {name} both as a free-form field and in filters"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reads the flattened `filter.*` parameters back, `None` when there are none.
+fn non_empty_filters<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<TrackFilters>, D::Error> {
+    let filters = TrackFilters::deserialize(deserializer)?;
+    Ok((filters != TrackFilters::default()).then_some(filters))
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -22,3 +72,39 @@ pub struct Paging {
     pub offset: Option<i32>,
     pub linked_partitioning: Option<bool>,
 }
+
+impl Paging {
+    /// `pagination` (or the defaults) with `linked_partitioning` on, as stream variants need.
+    pub(crate) fn linked(pagination: Option<&Paging>) -> Paging {
+        Paging {
+            linked_partitioning: Some(true),
+            ..pagination.cloned().unwrap_or_default()
+        }
+    }
+}
+
+/// Track metadata sent when uploading or editing a track. Fields left as `None` are not changed.
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct TrackParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genre: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_list: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing: Option<Sharing>,
+    /// Release date as `YYYY-MM-DD`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isrc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloadable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::response::{Extra, tracks::Track};
+
+/// A page of a top or trending chart. Fetch further pages with
+/// [`crate::Client::get_next_chart_page`].
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Chart {
+    pub collection: Vec<ChartEntry>,
+    pub next_href: Option<String>,
+    /// `top` or `trending`
+    pub kind: Option<String>,
+    pub genre: Option<ChartGenre>,
+    pub last_updated: Option<String>,
+    pub query_urn: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ChartEntry {
+    pub track: Option<Track>,
+    pub score: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct ChartGenre {
+    pub urn: Option<String>,
+    pub title: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
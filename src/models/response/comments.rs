@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::ResourceKind;
+use crate::models::ids::{TrackId, UserId};
+use crate::models::response::{Extra, PagingCollection, users::UserSummary};
+
+pub type Comments = PagingCollection<Comment>;
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Comment {
+    pub id: Option<i64>,
+    pub urn: Option<String>,
+    pub kind: Option<ResourceKind>,
+    pub body: Option<String>,
+    /// Playback position the comment is anchored to, in milliseconds
+    pub timestamp: Option<i64>,
+    pub created_at: Option<String>,
+    pub track_id: Option<TrackId>,
+    pub user_id: Option<UserId>,
+    pub user: Option<UserSummary>,
+    /// URN of the thread the comment belongs to
+    pub thread_urn: Option<String>,
+    /// Set on replies: the comment this one answers
+    pub parent_comment_id: Option<i64>,
+    pub reply_count: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Comment {
+    pub fn is_reply(&self) -> bool {
+        self.parent_comment_id.is_some()
+    }
+}
@@ -0,0 +1,200 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use serde::de::{self, DeserializeOwned, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use serde_path_to_error::Segment;
+
+use crate::models::error::Error;
+
+/// Key that carries lenient parsing warnings into [`Extra`]
+const WARNINGS_KEY: &str = "$warnings";
+
+/// Most fields lenient parsing drops from one response before giving up. Every dropped
+/// field means parsing the response again, so this bounds the work spent on a response
+/// that does not match the models at all. Enough for a field changing type on every item
+/// of a full page.
+const MAX_DROPPED_FIELDS: usize = 256;
+
+thread_local! {
+    /// Where inside a buffered value the last [`from_buffered`] error happened
+    static BUFFERED_ERROR_PATH: RefCell<Vec<Segment>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Fields of a response that have no typed counterpart on the model, kept so nothing the
+/// API adds is lost, plus the fields dropped by lenient parsing.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Extra {
+    pub fields: Map<String, Value>,
+    /// Fields whose value did not match the model, see [`crate::ClientBuilder::with_lenient_parsing`]
+    pub warnings: Vec<FieldWarning>,
+}
+
+impl Extra {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.get(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.warnings.is_empty()
+    }
+}
+
+/// A field left out of a model because its value had an unexpected type
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct FieldWarning {
+    /// Location of the field in the response, e.g. `collection[2].duration`
+    pub path: String,
+    pub message: String,
+    /// The value as sent by the API
+    pub value: Value,
+}
+
+impl Serialize for Extra {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (key, value) in &self.fields {
+            map.serialize_entry(key, value)?;
+        }
+        if !self.warnings.is_empty() {
+            map.serialize_entry(WARNINGS_KEY, &self.warnings)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Extra {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ExtraVisitor;
+
+        impl<'de> Visitor<'de> for ExtraVisitor {
+            type Value = Extra;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Extra, A::Error> {
+                let mut extra = Extra::default();
+                while let Some((key, value)) = access.next_entry::<String, Value>()? {
+                    if key == WARNINGS_KEY
+                        && let Ok(warnings) = serde_json::from_value(value.clone())
+                    {
+                        extra.warnings = warnings;
+                        continue;
+                    }
+                    extra.fields.insert(key, value);
+                }
+                Ok(extra)
+            }
+        }
+
+        deserializer.deserialize_map(ExtraVisitor)
+    }
+}
+
+/// Parse `text`, dropping fields that fail to deserialize instead of failing the whole
+/// value. Each dropped field becomes a [`FieldWarning`] on the nearest enclosing object,
+/// and a dropped list item on the object holding the list. Fails after
+/// [`MAX_DROPPED_FIELDS`] fields.
+pub(crate) fn from_str_lenient<R: DeserializeOwned>(text: &str) -> Result<R, Error> {
+    let mut value: Value = serde_json::from_str(text)?;
+    for _ in 0..=MAX_DROPPED_FIELDS {
+        BUFFERED_ERROR_PATH.take();
+        let error = match serde_path_to_error::deserialize::<_, R>(&value) {
+            Ok(parsed) => return Ok(parsed),
+            Err(error) => error,
+        };
+        let mut segments: Vec<Segment> = error.path().iter().cloned().collect();
+        segments.extend(BUFFERED_ERROR_PATH.take());
+        let warning = FieldWarning {
+            path: display_path(&segments),
+            message: error.inner().to_string(),
+            value: Value::Null,
+        };
+        let segments: Vec<&Segment> = segments.iter().collect();
+        if !drop_field(&mut value, &segments, warning) {
+            return Err(Error::from(error.into_inner()));
+        }
+    }
+    Err(Error::new(format!(
+        "Response has more than {MAX_DROPPED_FIELDS} fields that do not match the model"
+    )))
+}
+
+/// Deserialize a value that was buffered to look at its `kind` first. Buffering hides
+/// the path of an error from lenient parsing, so it is passed on separately.
+pub(crate) fn from_buffered<T: DeserializeOwned, E: de::Error>(value: Value) -> Result<T, E> {
+    BUFFERED_ERROR_PATH.take();
+    serde_path_to_error::deserialize(value).map_err(|error| {
+        let mut segments: Vec<Segment> = error.path().iter().cloned().collect();
+        // An error from a buffered value nested inside this one
+        segments.extend(BUFFERED_ERROR_PATH.take());
+        BUFFERED_ERROR_PATH.set(segments);
+        E::custom(error.into_inner())
+    })
+}
+
+/// Same format as [`serde_path_to_error::Path`], e.g. `collection[2].duration`
+fn display_path(segments: &[Segment]) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        if !path.is_empty() && !matches!(segment, Segment::Seq { .. }) {
+            path.push('.');
+        }
+        path.push_str(&segment.to_string());
+    }
+    path
+}
+
+fn drop_field(root: &mut Value, segments: &[&Segment], mut warning: FieldWarning) -> bool {
+    // Errors inside enums and flattened fields end in segments that cannot be followed
+    let end = segments
+        .iter()
+        .rposition(|segment| matches!(segment, Segment::Seq { .. } | Segment::Map { .. }));
+    let Some(end) = end else {
+        return false;
+    };
+    let (parent_path, last) = (&segments[..end], segments[end]);
+    let Some(parent) = follow(root, parent_path) else {
+        return false;
+    };
+    let removed = match (last, parent) {
+        (Segment::Map { key }, Value::Object(map)) => map.remove(key),
+        (Segment::Seq { index }, Value::Array(items)) if *index < items.len() => {
+            Some(items.remove(*index))
+        }
+        _ => None,
+    };
+    let Some(removed) = removed else {
+        return false;
+    };
+    warning.value = removed;
+
+    let holder = (0..=parent_path.len())
+        .rev()
+        .find(|&len| matches!(follow(root, &parent_path[..len]), Some(Value::Object(_))));
+    if let Some(len) = holder
+        && let Some(Value::Object(map)) = follow(root, &parent_path[..len])
+    {
+        let warnings = map
+            .entry(WARNINGS_KEY)
+            .or_insert_with(|| Value::Array(Vec::new()));
+        if let (Value::Array(warnings), Ok(warning)) = (warnings, serde_json::to_value(warning)) {
+            warnings.push(warning);
+        }
+    }
+    true
+}
+
+fn follow<'a>(value: &'a mut Value, segments: &[&Segment]) -> Option<&'a mut Value> {
+    segments
+        .iter()
+        .try_fold(value, |value, segment| match (segment, value) {
+            (Segment::Map { key }, Value::Object(map)) => map.get_mut(key),
+            (Segment::Seq { index }, Value::Array(items)) => items.get_mut(*index),
+            _ => None,
+        })
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ids::TrackId;
+use crate::models::response::{Extra, PagingCollection};
+use crate::response::Track;
+
+pub type PlayHistory = PagingCollection<PlayHistoryItem>;
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct PlayHistoryItem {
+    /// Milliseconds since the UNIX epoch
+    pub played_at: Option<i64>,
+    pub track_id: Option<TrackId>,
+    pub track: Option<Track>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
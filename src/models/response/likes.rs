@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::ResourceKind;
+use crate::models::response::{Extra, PagingCollection};
+use crate::response::{Playlist, Track};
+
+pub type Likes = PagingCollection<Like>;
+
+/// A liked track or playlist, wrapped with the time it was liked
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Like {
+    pub created_at: Option<String>,
+    pub kind: Option<ResourceKind>,
+    pub track: Option<Track>,
+    pub playlist: Option<Playlist>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
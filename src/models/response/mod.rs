@@ -1,11 +1,27 @@
+mod charts;
+mod comments;
+mod extra;
+mod history;
+mod likes;
 mod playlists;
+mod profile;
 mod reposts;
 mod search;
+mod selections;
+mod stations;
 mod tracks;
 mod users;
+pub use charts::*;
+pub use comments::*;
+pub use extra::*;
+pub use history::*;
+pub use likes::*;
 pub use playlists::*;
+pub use profile::*;
 pub use reposts::*;
 pub use search::*;
+pub use selections::*;
+pub use stations::*;
 pub use tracks::*;
 pub use users::*;
 
@@ -14,4 +30,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct PagingCollection<T> {
     pub collection: Vec<T>,
+    pub next_href: Option<String>,
+    /// Facet counts, returned by searches that request a `facet`
+    pub facets: Option<Vec<Facet>>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
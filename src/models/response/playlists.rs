@@ -1,37 +1,42 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::response::{PagingCollection, tracks::Track, users::UserSummary};
+use crate::models::enums::{EmbeddableBy, License, PlaylistType, ResourceKind, Sharing};
+use crate::models::ids::{PlaylistId, UserId};
+use crate::models::response::{Extra, PagingCollection, tracks::Track, users::UserSummary};
 
 pub type Playlists = PagingCollection<Playlist>;
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Playlist {
     pub title: Option<String>,
-    pub id: Option<i32>,
+    pub id: Option<PlaylistId>,
     pub urn: Option<String>,
-    pub kind: Option<String>,
+    pub kind: Option<ResourceKind>,
     pub artwork_url: Option<String>,
     pub created_at: Option<String>,
     pub description: Option<String>,
     pub downloadable: Option<bool>,
     pub duration: Option<i64>,
     pub ean: Option<String>,
-    pub embeddable_by: Option<String>,
+    pub embeddable_by: Option<EmbeddableBy>,
     pub genre: Option<String>,
-    pub label_id: Option<i32>,
+    pub label_id: Option<i64>,
     pub label_name: Option<String>,
     pub last_modified: Option<String>,
-    pub license: Option<String>,
+    pub license: Option<License>,
     pub permalink: Option<String>,
     pub permalink_url: Option<String>,
-    pub playlist_type: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
+    pub is_album: Option<bool>,
+    /// `album`, `ep`, `single` or `compilation` for albums
+    pub set_type: Option<PlaylistType>,
     pub purchase_title: Option<String>,
     pub purchase_url: Option<String>,
     pub release: Option<String>,
     pub release_day: Option<i32>,
     pub release_month: Option<i32>,
     pub release_year: Option<i32>,
-    pub sharing: Option<String>,
+    pub sharing: Option<Sharing>,
     pub streamable: Option<bool>,
     pub tag_list: Option<String>,
     pub track_count: Option<i32>,
@@ -39,7 +44,7 @@ pub struct Playlist {
     pub r#type: Option<String>,
     pub uri: Option<String>,
     pub user: Option<UserSummary>,
-    pub user_id: Option<i32>,
+    pub user_id: Option<UserId>,
     pub user_urn: Option<String>,
     pub likes_count: Option<i32>,
     pub label: Option<UserSummary>,
@@ -47,4 +52,30 @@ pub struct Playlist {
     pub tags: Option<String>,
     pub monetization_model: Option<String>,
     pub policy: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// A playlist generated by SoundCloud, e.g. a daily mix made for a user
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct SystemPlaylist {
+    /// URN-like id, e.g. `soundcloud:system-playlists:...`
+    pub id: Option<String>,
+    pub urn: Option<String>,
+    pub kind: Option<ResourceKind>,
+    pub title: Option<String>,
+    pub short_title: Option<String>,
+    pub description: Option<String>,
+    pub short_description: Option<String>,
+    pub permalink: Option<String>,
+    pub permalink_url: Option<String>,
+    pub artwork_url: Option<String>,
+    pub calculated_artwork_url: Option<String>,
+    pub last_updated: Option<String>,
+    pub is_public: Option<bool>,
+    pub made_for: Option<UserSummary>,
+    pub user: Option<UserSummary>,
+    pub tracks: Option<Vec<Track>>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
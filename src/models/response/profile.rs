@@ -0,0 +1,62 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::models::response::extra::from_buffered;
+use crate::models::response::search::serialize_with_kind;
+use crate::models::response::{Extra, PagingCollection, playlists::Playlist, tracks::Track};
+
+pub type Spotlight = PagingCollection<SpotlightItem>;
+
+/// A track or playlist pinned to the top of a user's profile
+#[derive(Debug, Clone)]
+pub enum SpotlightItem {
+    Track(Track),
+    Playlist(Playlist),
+    /// A kind without a variant, e.g. a `system-playlist`, kept as raw JSON so the rest of
+    /// the list still parses
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for SpotlightItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+        let item = match kind.as_str() {
+            "track" => SpotlightItem::Track(from_buffered(value)?),
+            "playlist" => SpotlightItem::Playlist(from_buffered(value)?),
+            _ => SpotlightItem::Other(value),
+        };
+        Ok(item)
+    }
+}
+
+impl Serialize for SpotlightItem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SpotlightItem::Track(track) => serialize_with_kind(track, "track", serializer),
+            SpotlightItem::Playlist(playlist) => {
+                serialize_with_kind(playlist, "playlist", serializer)
+            }
+            SpotlightItem::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// An external link shown on a user's profile
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct WebProfile {
+    pub id: Option<i64>,
+    /// e.g. `instagram`, `twitter` or `personal`
+    #[serde(alias = "network")]
+    pub service: Option<String>,
+    pub url: Option<String>,
+    pub title: Option<String>,
+    pub username: Option<String>,
+    pub created_at: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::response::Extra;
+
 use crate::{
     models::response::PagingCollection,
-    response::{Track, User},
+    response::{Playlist, Track, User},
 };
 
 pub type Reposts = PagingCollection<Repost>;
@@ -15,4 +17,7 @@ pub struct Repost {
     pub uuid: Option<String>,
     pub caption: Option<String>,
     pub track: Option<Track>,
+    pub playlist: Option<Playlist>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
@@ -1,6 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::response::{PagingCollection, Playlist, Track, User};
+use crate::models::enums::ResourceKind;
+use crate::models::response::Extra;
+use crate::models::response::extra::from_buffered;
+use serde_json::Value;
+
+use crate::response::{PagingCollection, Playlist, SystemPlaylist, Track, User};
 
 pub type SearchResultsResponse = PagingCollection<SearchResult>;
 pub type SearchAllResponse = PagingCollection<SearchAllResult>;
@@ -9,12 +14,228 @@ pub type SearchAllResponse = PagingCollection<SearchAllResult>;
 pub struct SearchResult {
     pub output: Option<String>,
     pub query: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(tag = "kind", rename_all = "lowercase")]
+/// An entry of a mixed search page, told apart by its `kind`
+#[derive(Debug, Clone)]
 pub enum SearchAllResult {
     Track(Track),
     User(User),
     Playlist(Playlist),
+    /// A playlist with `is_album` set
+    Album(Playlist),
+    SystemPlaylist(SystemPlaylist),
+    /// Any other kind, kept as raw JSON so the rest of the page still parses
+    Other(Value),
+}
+
+impl SearchAllResult {
+    pub fn kind(&self) -> &str {
+        match self {
+            SearchAllResult::Track(_) => "track",
+            SearchAllResult::User(_) => "user",
+            SearchAllResult::Playlist(_) | SearchAllResult::Album(_) => "playlist",
+            SearchAllResult::SystemPlaylist(_) => "system-playlist",
+            SearchAllResult::Other(value) => {
+                value.get("kind").and_then(Value::as_str).unwrap_or("")
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchAllResult {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+        let result = match kind.as_str() {
+            "track" => SearchAllResult::Track(from_buffered(value)?),
+            "user" => SearchAllResult::User(from_buffered(value)?),
+            "playlist" | "album" => {
+                let playlist: Playlist = from_buffered(value)?;
+                if kind == "album" || playlist.is_album == Some(true) {
+                    SearchAllResult::Album(playlist)
+                } else {
+                    SearchAllResult::Playlist(playlist)
+                }
+            }
+            "system-playlist" => SearchAllResult::SystemPlaylist(from_buffered(value)?),
+            _ => SearchAllResult::Other(value),
+        };
+        Ok(result)
+    }
+}
+
+impl Serialize for SearchAllResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let kind = self.kind();
+        match self {
+            SearchAllResult::Track(track) => serialize_with_kind(track, kind, serializer),
+            SearchAllResult::User(user) => serialize_with_kind(user, kind, serializer),
+            SearchAllResult::Playlist(playlist) | SearchAllResult::Album(playlist) => {
+                serialize_with_kind(playlist, kind, serializer)
+            }
+            SearchAllResult::SystemPlaylist(playlist) => {
+                serialize_with_kind(playlist, kind, serializer)
+            }
+            SearchAllResult::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// Serialize `item` with its `kind` set, for the entries of mixed lists
+pub(crate) fn serialize_with_kind<S: Serializer>(
+    item: &impl Serialize,
+    kind: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut value = serde_json::to_value(item).map_err(serde::ser::Error::custom)?;
+    if let Value::Object(map) = &mut value {
+        map.insert("kind".to_string(), Value::String(kind.to_string()));
+    }
+    value.serialize(serializer)
+}
+
+/// A mixed search page split by kind, see [`PagingCollection::split`]
+#[derive(Debug, Default, Clone)]
+pub struct SearchAllGroups {
+    pub tracks: Vec<Track>,
+    pub users: Vec<User>,
+    pub playlists: Vec<Playlist>,
+    pub albums: Vec<Playlist>,
+    pub system_playlists: Vec<SystemPlaylist>,
+    pub other: Vec<Value>,
+}
+
+impl From<Vec<SearchAllResult>> for SearchAllGroups {
+    fn from(results: Vec<SearchAllResult>) -> Self {
+        let mut groups = SearchAllGroups::default();
+        for result in results {
+            match result {
+                SearchAllResult::Track(track) => groups.tracks.push(track),
+                SearchAllResult::User(user) => groups.users.push(user),
+                SearchAllResult::Playlist(playlist) => groups.playlists.push(playlist),
+                SearchAllResult::Album(album) => groups.albums.push(album),
+                SearchAllResult::SystemPlaylist(playlist) => groups.system_playlists.push(playlist),
+                SearchAllResult::Other(value) => groups.other.push(value),
+            }
+        }
+        groups
+    }
+}
+
+impl PagingCollection<SearchAllResult> {
+    /// Split the page into one vector per kind, keeping the order within each kind.
+    pub fn split(self) -> SearchAllGroups {
+        SearchAllGroups::from(self.collection)
+    }
+}
+
+/// Result counts grouped by a field, e.g. `genre`
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Facet {
+    pub name: Option<String>,
+    pub facets: Option<Vec<FacetValue>>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct FacetValue {
+    pub value: Option<String>,
+    pub count: Option<i64>,
+    /// Filter parameter that narrows a search to this value
+    pub filter: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// A single autocomplete suggestion: a query to search for, or a track, user or playlist
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Suggestion {
+    /// `Query`, `Track`, `User` or `Playlist`
+    pub kind: Option<ResourceKind>,
+    pub query: Option<String>,
+    /// Suggestion text with the parts matching the input wrapped in `<b>` tags
+    pub output: Option<String>,
+    /// Id of the suggested track, user or playlist. Kept raw since which id type it is
+    /// depends on `kind`.
+    pub id: Option<i64>,
+    pub urn: Option<String>,
+    pub permalink_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+impl Suggestion {
+    pub fn is_query(&self) -> bool {
+        self.id.is_none()
+            && self
+                .kind
+                .as_ref()
+                .is_none_or(|kind| *kind == ResourceKind::Query)
+    }
+
+    /// `output` without highlight tags.
+    pub fn text(&self) -> String {
+        self.highlighted().0
+    }
+
+    /// Character ranges of `text()` that match the input.
+    pub fn highlights(&self) -> Vec<std::ops::Range<usize>> {
+        self.highlighted().1
+    }
+
+    fn highlighted(&self) -> (String, Vec<std::ops::Range<usize>>) {
+        let output = self
+            .output
+            .as_deref()
+            .or(self.query.as_deref())
+            .unwrap_or("");
+        let mut text = String::new();
+        let mut ranges = Vec::new();
+        let mut start = None;
+        let mut rest = output;
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix("<b>") {
+                start = Some(text.chars().count());
+                rest = after;
+            } else if let Some(after) = rest.strip_prefix("</b>") {
+                if let Some(start) = start.take() {
+                    ranges.push(start..text.chars().count());
+                }
+                rest = after;
+            } else {
+                let c = rest.chars().next().unwrap_or_default();
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        (text, ranges)
+    }
+}
+
+pub type Suggestions = PagingCollection<Suggestion>;
+
+/// Autocomplete suggestions split into queries and entities
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Autocomplete {
+    pub queries: Vec<Suggestion>,
+    /// Suggested tracks, users and playlists
+    pub entities: Vec<Suggestion>,
+}
+
+impl From<Suggestions> for Autocomplete {
+    fn from(suggestions: Suggestions) -> Self {
+        let (queries, entities) = suggestions
+            .collection
+            .into_iter()
+            .partition(Suggestion::is_query);
+        Self { queries, entities }
+    }
 }
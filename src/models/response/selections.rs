@@ -0,0 +1,72 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::models::enums::ResourceKind;
+use crate::models::response::Extra;
+use crate::models::response::extra::from_buffered;
+use crate::models::response::search::serialize_with_kind;
+
+use crate::models::response::{
+    PagingCollection,
+    playlists::{Playlist, SystemPlaylist},
+    tracks::Track,
+};
+
+pub type Selections = PagingCollection<Selection>;
+
+/// A curated row of the discover feed
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Selection {
+    pub id: Option<String>,
+    pub urn: Option<String>,
+    pub kind: Option<ResourceKind>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub style: Option<String>,
+    pub last_updated: Option<String>,
+    pub items: Option<PagingCollection<SelectionItem>>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone)]
+pub enum SelectionItem {
+    Track(Track),
+    Playlist(Playlist),
+    SystemPlaylist(SystemPlaylist),
+    /// Item kinds without a model yet, kept as raw JSON
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for SelectionItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+        let item = match kind.as_str() {
+            "track" => SelectionItem::Track(from_buffered(value)?),
+            "playlist" => SelectionItem::Playlist(from_buffered(value)?),
+            "system-playlist" => SelectionItem::SystemPlaylist(from_buffered(value)?),
+            _ => SelectionItem::Other(value),
+        };
+        Ok(item)
+    }
+}
+
+impl Serialize for SelectionItem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SelectionItem::Track(track) => serialize_with_kind(track, "track", serializer),
+            SelectionItem::Playlist(playlist) => {
+                serialize_with_kind(playlist, "playlist", serializer)
+            }
+            SelectionItem::SystemPlaylist(playlist) => {
+                serialize_with_kind(playlist, "system-playlist", serializer)
+            }
+            SelectionItem::Other(value) => value.serialize(serializer),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::enums::ResourceKind;
+use crate::models::response::{Extra, tracks::Tracks, users::UserSummary};
+
+/// A radio station seeded by a track or an artist
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct Station {
+    /// e.g. `soundcloud:track-stations:123` or `soundcloud:artist-stations:456`
+    pub urn: Option<String>,
+    pub kind: Option<ResourceKind>,
+    /// `track-station` or `artist-station`
+    pub r#type: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub permalink: Option<String>,
+    pub permalink_url: Option<String>,
+    pub artwork_url: Option<String>,
+    pub last_updated: Option<String>,
+    pub user: Option<UserSummary>,
+    /// First page of the station's tracks
+    pub tracks: Option<Tracks>,
+    #[serde(flatten)]
+    pub extra: Extra,
+}
@@ -1,12 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::response::{PagingCollection, users::UserSummary};
+use crate::models::enums::{Access, EmbeddableBy, License, ResourceKind, Sharing};
+use crate::models::ids::TrackId;
+use crate::models::response::{Extra, PagingCollection, users::UserSummary};
 
 pub type Tracks = PagingCollection<Track>;
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Track {
-    pub access: Option<String>,
+    pub access: Option<Access>,
     pub artwork_url: Option<String>,
     pub bpm: Option<f64>,
     pub comment_count: Option<i64>,
@@ -15,14 +17,15 @@ pub struct Track {
     pub download_url: Option<String>,
     pub downloadable: Option<bool>,
     pub duration: Option<i64>,
-    pub embeddable_by: Option<String>,
+    pub embeddable_by: Option<EmbeddableBy>,
     pub favoritings_count: Option<i64>,
     pub genre: Option<String>,
-    pub id: Option<i64>,
+    pub id: Option<TrackId>,
     pub isrc: Option<String>,
-    pub kind: Option<String>,
+    pub kind: Option<ResourceKind>,
     pub label_name: Option<String>,
-    pub license: Option<String>,
+    pub last_modified: Option<String>,
+    pub license: Option<License>,
     pub media: Option<Media>,
     pub permalink_url: Option<String>,
     pub playback_count: Option<i64>,
@@ -34,7 +37,9 @@ pub struct Track {
     pub release_month: Option<i32>,
     pub release_year: Option<i32>,
     pub reposts_count: Option<i64>,
-    pub sharing: Option<String>,
+    pub sharing: Option<Sharing>,
+    /// Processing state of an upload: `processing`, `finished` or `failed`
+    pub state: Option<String>,
     pub stream_url: Option<String>,
     pub streamable: Option<bool>,
     pub tag_list: Option<String>,
@@ -44,6 +49,8 @@ pub struct Track {
     pub user_favorite: Option<bool>,
     pub user_playback_count: Option<i64>,
     pub waveform_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -51,11 +58,15 @@ pub struct PublisherMetadata {
     pub id: Option<i64>,
     pub urn: Option<String>,
     pub contains_music: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Media {
     pub transcodings: Option<Vec<Transcoding>>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -67,12 +78,16 @@ pub struct Transcoding {
     pub format: Option<TranscodingFormat>,
     pub quality: Option<String>,
     pub is_legacy_transcoding: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct TranscodingFormat {
     pub protocol: Option<StreamType>,
     pub mime_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -87,6 +102,8 @@ pub enum StreamType {
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Stream {
     pub url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -94,4 +111,6 @@ pub struct Waveform {
     pub samples: Option<Vec<f64>>,
     pub width: Option<i32>,
     pub height: Option<i32>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::response::PagingCollection;
+use crate::models::enums::ResourceKind;
+use crate::models::ids::UserId;
+use crate::models::response::{Extra, PagingCollection};
 
 pub type Users = PagingCollection<User>;
 
@@ -19,8 +21,8 @@ pub struct User {
     pub first_name: Option<String>,
     pub full_name: Option<String>,
     pub groups_count: Option<i32>,
-    pub id: Option<i64>,
-    pub kind: Option<String>,
+    pub id: Option<UserId>,
+    pub kind: Option<ResourceKind>,
     pub last_modified: Option<String>,
     pub last_name: Option<String>,
     pub likes_count: Option<i32>,
@@ -39,16 +41,22 @@ pub struct User {
     pub station_urn: Option<String>,
     pub station_permalink: Option<String>,
     pub date_of_birth: Option<DateOfBirth>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct CreatorSubscriptionWrapper {
     pub product: Product,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Product {
     pub id: String,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -56,6 +64,8 @@ pub struct Visuals {
     pub urn: Option<String>,
     pub enabled: Option<bool>,
     pub visuals: Option<Vec<VisualEntry>>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -63,6 +73,8 @@ pub struct VisualEntry {
     pub urn: Option<String>,
     pub entry_time: Option<i32>,
     pub visual_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -71,6 +83,8 @@ pub struct Badges {
     pub creator_mid_tier: Option<bool>,
     pub pro_unlimited: Option<bool>,
     pub verified: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -78,12 +92,16 @@ pub struct DateOfBirth {
     pub month: Option<i8>,
     pub year: Option<i16>,
     pub day: Option<i8>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct UserSummary {
-    pub id: Option<i64>,
+    pub id: Option<UserId>,
     pub username: Option<String>,
     pub permalink_url: Option<String>,
     pub avatar_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Extra,
 }
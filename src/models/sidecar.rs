@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::models::error::Error;
+use crate::models::export::{read_json, write_json};
+use crate::response::{StreamType, Track, Transcoding};
+
+/// File naming for metadata sidecars written next to downloaded tracks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidecarFormat {
+    /// `song.mp3` -> `song.json`
+    Json,
+    /// `song.mp3` -> `song.info.json`
+    InfoJson,
+}
+
+impl SidecarFormat {
+    /// Sidecar path for the audio file at `output_path`.
+    pub fn path_for(&self, output_path: &Path) -> PathBuf {
+        match self {
+            SidecarFormat::Json => output_path.with_extension("json"),
+            SidecarFormat::InfoJson => output_path.with_extension("info.json"),
+        }
+    }
+}
+
+/// Metadata written next to a downloaded track so it can be indexed without the API
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrackSidecar {
+    pub track: Track,
+    pub transcoding: Transcoding,
+    pub stream_type: StreamType,
+    /// Seconds since the UNIX epoch
+    pub downloaded_at: u64,
+    /// Permalink of the track page, stream URLs expire shortly after the download
+    pub source_url: Option<String>,
+}
+
+impl TrackSidecar {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        read_json(path.as_ref())
+    }
+
+    /// Load a sidecar and return only the track it describes.
+    pub fn load_track(path: impl AsRef<Path>) -> Result<Track, Error> {
+        Ok(Self::load(path)?.track)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        write_json(path.as_ref(), self)
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::models::archive::write_atomically;
+use crate::models::error::Error;
+use crate::models::ids::{PlaylistId, TrackId};
+use crate::response::{StreamType, User};
+
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Which parts of a user's profile to mirror
+#[derive(Debug, Clone)]
+pub struct SyncOptions {
+    pub include_uploads: bool,
+    pub include_reposts: bool,
+    pub include_playlists: bool,
+    pub stream_type: Option<StreamType>,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        Self {
+            include_uploads: true,
+            include_reposts: true,
+            include_playlists: true,
+            stream_type: None,
+        }
+    }
+}
+
+/// Why a track is part of the mirror
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MirrorSource {
+    Upload,
+    Repost,
+    Playlist,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MirrorTrack {
+    pub id: TrackId,
+    pub urn: Option<String>,
+    pub title: Option<String>,
+    pub permalink_url: Option<String>,
+    pub last_modified: Option<String>,
+    pub sources: Vec<MirrorSource>,
+    /// Path of the audio file relative to the mirror directory
+    pub path: Option<PathBuf>,
+    pub downloaded_at: Option<u64>,
+    pub removed: bool,
+    pub removed_at: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MirrorPlaylist {
+    pub id: PlaylistId,
+    pub urn: Option<String>,
+    pub title: Option<String>,
+    pub permalink_url: Option<String>,
+    pub last_modified: Option<String>,
+    /// Track ids in playlist order
+    pub track_ids: Vec<TrackId>,
+    pub removed: bool,
+    pub removed_at: Option<u64>,
+}
+
+/// State of a mirror directory, written to `manifest.json` after every sync
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct SyncManifest {
+    pub user: Option<User>,
+    pub synced_at: Option<u64>,
+    pub tracks: Vec<MirrorTrack>,
+    pub playlists: Vec<MirrorPlaylist>,
+}
+
+impl SyncManifest {
+    /// Load the manifest from a mirror directory, or start an empty one.
+    pub fn load(directory: &Path) -> Result<Self, Error> {
+        let path = directory.join(MANIFEST_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Write the manifest into a mirror directory.
+    pub fn save(&self, directory: &Path) -> Result<(), Error> {
+        std::fs::create_dir_all(directory)?;
+        let path = directory.join(MANIFEST_FILE_NAME);
+        write_atomically(&path, &serde_json::to_vec_pretty(self)?)
+    }
+
+    pub fn track(&self, id: TrackId) -> Option<&MirrorTrack> {
+        self.tracks.iter().find(|t| t.id == id)
+    }
+
+    pub fn playlist(&self, id: PlaylistId) -> Option<&MirrorPlaylist> {
+        self.playlists.iter().find(|p| p.id == id)
+    }
+}
+
+/// Summary of what a single sync run changed
+#[derive(Debug, Default, Clone)]
+pub struct SyncReport {
+    pub downloaded: Vec<TrackId>,
+    pub updated: Vec<TrackId>,
+    pub removed: Vec<TrackId>,
+    pub unchanged: Vec<TrackId>,
+    /// New or changed tracks not downloaded because they are in the download archive
+    pub skipped: Vec<TrackId>,
+    pub failed: Vec<(TrackId, String)>,
+}
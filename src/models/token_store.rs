@@ -0,0 +1,156 @@
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::models::auth::{Token, random_string};
+use crate::models::error::Error;
+
+/// Persistent storage for OAuth tokens.
+///
+/// The client loads the token from the store when it is built and writes every new or
+/// refreshed token back. Implementations shared between processes should override
+/// [`TokenStore::lock`] so only one process refreshes at a time.
+pub trait TokenStore: Send + Sync + fmt::Debug {
+    fn load(&self) -> Result<Option<Token>, Error>;
+
+    fn save(&self, token: &Token) -> Result<(), Error>;
+
+    fn clear(&self) -> Result<(), Error>;
+
+    /// Take an exclusive lock held while a token is refreshed. Blocks until it is acquired.
+    fn lock(&self) -> Result<TokenStoreLock, Error> {
+        Ok(TokenStoreLock::default())
+    }
+}
+
+/// Guard returned by [`TokenStore::lock`], the lock is released when it is dropped
+#[derive(Debug, Default)]
+pub struct TokenStoreLock {
+    _file: Option<File>,
+}
+
+impl TokenStoreLock {
+    /// A lock backed by an exclusively locked file.
+    pub fn from_file(file: File) -> Self {
+        Self { _file: Some(file) }
+    }
+}
+
+/// Keeps the token in memory only
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<Token>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<Token>, Error> {
+        Ok(self.token.lock().expect("Token store poisoned").clone())
+    }
+
+    fn save(&self, token: &Token) -> Result<(), Error> {
+        *self.token.lock().expect("Token store poisoned") = Some(token.clone());
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        *self.token.lock().expect("Token store poisoned") = None;
+        Ok(())
+    }
+}
+
+/// Stores the token as JSON in a file readable only by the current user.
+/// A sibling `.lock` file coordinates refreshes between processes sharing the file.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    pub path: PathBuf,
+}
+
+impl FileTokenStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn lock_path(&self) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(".lock");
+        self.path.with_file_name(name)
+    }
+
+    /// A temporary file next to the token, unique so concurrent writers never share one.
+    fn tmp_path(&self) -> PathBuf {
+        let mut name = OsString::from(".");
+        name.push(self.path.file_name().unwrap_or_default());
+        name.push(format!(".{}.{}.tmp", std::process::id(), random_string(8)));
+        self.path.with_file_name(name)
+    }
+
+    fn create_dir(&self) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(())
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Option<Token>, Error> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let text = std::fs::read_to_string(&self.path)?;
+        Ok(Some(serde_json::from_str(&text)?))
+    }
+
+    fn save(&self, token: &Token) -> Result<(), Error> {
+        self.create_dir()?;
+        let tmp_path = self.tmp_path();
+        let mut file = private_file_options().open(&tmp_path)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(&serde_json::to_vec_pretty(token)?)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        if self.path.exists() {
+            std::fs::remove_file(&self.path)?;
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<TokenStoreLock, Error> {
+        self.create_dir()?;
+        let file = private_file_options()
+            .truncate(false)
+            .open(self.lock_path())?;
+        file.lock()?;
+        Ok(TokenStoreLock::from_file(file))
+    }
+}
+
+fn private_file_options() -> OpenOptions {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+}
@@ -0,0 +1,25 @@
+/// Wait this long between checks while an uploaded track is being processed
+pub const UPLOAD_POLL_INTERVAL_SECS: u64 = 2;
+
+/// Give up waiting for an uploaded track to be processed after this long
+pub const UPLOAD_PROCESSING_TIMEOUT_SECS: u64 = 30 * 60;
+
+/// Audio is streamed to the server in chunks of this size
+pub(crate) const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Progress of an audio upload, reported after every chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    pub bytes_sent: u64,
+    pub total_bytes: u64,
+}
+
+impl UploadProgress {
+    /// Fraction of the file sent so far, between 0.0 and 1.0.
+    pub fn fraction(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return 1.0;
+        }
+        self.bytes_sent as f64 / self.total_bytes as f64
+    }
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{AccountConfig, OAuthConfig, Token, TrackId};
+
+fn account(access_token: &str) -> AccountConfig {
+    AccountConfig {
+        oauth_config: Some(OAuthConfig::authorization_code(
+            "app",
+            None,
+            "http://127.0.0.1:8080/callback",
+        )),
+        token: Some(Token {
+            access_token: access_token.to_string(),
+            refresh_token: None,
+            token_type: None,
+            scope: None,
+            expires_at: None,
+        }),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_requests_run_as_named_accounts() {
+    let server = StandInServer::start(|_| (200, r#"{"id":1}"#.to_string())).await;
+    let client = server
+        .builder()
+        .with_client_id("shared")
+        .with_account("label-a", account("token-a"))
+        .build()
+        .await
+        .unwrap();
+    client
+        .add_account(
+            "label-b",
+            AccountConfig {
+                client_id: Some("own".to_string()),
+                ..account("token-b")
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(client.account_names(), vec!["label-a", "label-b"]);
+
+    let names = client.account_names();
+    let results = futures::future::join_all(names.iter().map(|name| {
+        let client = &client;
+        async move {
+            let account = client.account(name)?;
+            account.repost_track(TrackId(9)).await
+        }
+    }))
+    .await;
+    assert!(results.iter().all(|r| r.is_ok()));
+
+    let mut seen: Vec<(String, bool)> = server
+        .requests()
+        .iter()
+        .map(|r| {
+            (
+                r.header("authorization").unwrap().to_string(),
+                r.target.contains("client_id=own"),
+            )
+        })
+        .collect();
+    seen.sort();
+    assert_eq!(
+        seen,
+        vec![
+            ("OAuth token-a".to_string(), false),
+            ("OAuth token-b".to_string(), true),
+        ]
+    );
+
+    // The default client stays anonymous
+    client.repost_track(TrackId(9)).await.unwrap();
+    assert_eq!(
+        server.requests().last().unwrap().header("authorization"),
+        None
+    );
+
+    assert!(client.account("missing").is_err());
+    assert!(client.remove_account("label-a").is_some());
+    assert_eq!(client.account_names(), vec!["label-b"]);
+}
@@ -0,0 +1,199 @@
+mod common;
+
+use common::{StandInServer, catalog, temp_dir};
+use soundcloud_rs::{
+    ArchiveEntry, DownloadArchive, TrackId,
+    response::{Track, Transcoding},
+};
+use std::sync::{Arc, Mutex};
+
+fn track(id: i64) -> Track {
+    Track {
+        id: Some(TrackId(id)),
+        urn: Some(format!("soundcloud:tracks:{id}")),
+        ..Default::default()
+    }
+}
+
+fn transcoding(quality: &str) -> Transcoding {
+    Transcoding {
+        quality: Some(quality.to_string()),
+        preset: Some("mp3_0_0".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_archive_roundtrip_and_lookup() {
+    let dir = temp_dir("archive");
+    let audio_path = dir.join("song.mp3");
+    std::fs::write(&audio_path, b"audio").expect("Failed to write audio");
+
+    let archive_path = dir.join("archive.json");
+    let mut archive = DownloadArchive::open(&archive_path).expect("Failed to open archive");
+    assert!(archive.entries.is_empty(), "New archive should be empty");
+
+    let entry = ArchiveEntry::from_download(&track(1), &transcoding("sq"), &audio_path)
+        .expect("Failed to build entry");
+    assert_eq!(entry.size, 5);
+    assert_eq!(
+        entry.checksum,
+        "6ed8919ce20490a5e3ad8630a4fab69475297abd07db73918dd5f36fcfaeb11b"
+    );
+    archive.record(entry);
+    archive.save().expect("Failed to save archive");
+
+    // Lookups only need the id or the URN, so renamed files are still found
+    let reopened = DownloadArchive::open(&archive_path).expect("Failed to reopen archive");
+    let by_urn = Track {
+        urn: Some("soundcloud:tracks:1".to_string()),
+        ..Default::default()
+    };
+    assert!(reopened.find(&track(1)).is_some());
+    assert!(reopened.find(&by_urn).is_some());
+    assert!(reopened.find(&track(2)).is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_archive_record_replaces_and_detects_upgrades() {
+    let dir = temp_dir("upgrade");
+    let audio_path = dir.join("song.mp3");
+    std::fs::write(&audio_path, b"audio").expect("Failed to write audio");
+
+    let mut archive = DownloadArchive::open(dir.join("archive.json")).expect("Failed to open archive");
+    let sq = ArchiveEntry::from_download(&track(1), &transcoding("sq"), &audio_path)
+        .expect("Failed to build entry");
+    assert!(DownloadArchive::is_upgrade(&sq, &transcoding("hq")));
+    assert!(!DownloadArchive::is_upgrade(&sq, &transcoding("sq")));
+
+    archive.record(sq);
+    let hq = ArchiveEntry::from_download(&track(1), &transcoding("hq"), &audio_path)
+        .expect("Failed to build entry");
+    archive.record(hq);
+    assert_eq!(archive.entries.len(), 1, "Re-recording a track should replace it");
+    assert_eq!(archive.entries[0].quality.as_deref(), Some("hq"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_upgrade_replaces_archived_file() {
+    let quality = Arc::new(Mutex::new("sq"));
+    let offered = quality.clone();
+    let server = StandInServer::start_linked(move |url, req| {
+        catalog(url, req, &offered.lock().unwrap()).unwrap_or((404, "{}".to_string()))
+    })
+    .await;
+    let dir = temp_dir("replace");
+    let client = server
+        .builder()
+        .with_download_archive(dir.join("archive.json"))
+        .with_redownload_on_upgrade(true)
+        .build()
+        .await
+        .unwrap();
+    let destination = dir.to_str().unwrap();
+
+    let sq_path = dir.join("song-sq.mp3");
+    client
+        .download_track(TrackId(1), None, Some(destination), Some("song-sq"))
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&sq_path).unwrap(), "audio 1 sq");
+
+    // Same quality again: skipped, the archived file stays
+    client
+        .download_track(TrackId(1), None, Some(destination), Some("song-hq"))
+        .await
+        .unwrap();
+    assert!(sq_path.exists());
+    assert!(!dir.join("song-hq.mp3").exists());
+
+    *quality.lock().unwrap() = "hq";
+    client
+        .download_track(TrackId(1), None, Some(destination), Some("song-hq"))
+        .await
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dir.join("song-hq.mp3")).unwrap(),
+        "audio 1 hq"
+    );
+    assert!(
+        !sq_path.exists(),
+        "The lower quality file should be removed"
+    );
+    let archive = DownloadArchive::open(dir.join("archive.json")).unwrap();
+    assert_eq!(archive.entries.len(), 1);
+    assert_eq!(archive.entries[0].output_path, dir.join("song-hq.mp3"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_upgrade_looks_past_the_first_transcoding() {
+    let qualities = Arc::new(Mutex::new(vec!["sq"]));
+    let offered = qualities.clone();
+    let server = StandInServer::start_linked(move |url, req| {
+        let path = req.path();
+        if path == "/tracks/1" {
+            let transcodings: Vec<String> = offered
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|q| {
+                    format!(
+                        r#"{{"url":"{url}/media/{q}","preset":"mp3_0_0","quality":"{q}","format":{{"protocol":"progressive"}}}}"#
+                    )
+                })
+                .collect();
+            let body = format!(
+                r#"{{"id":1,"urn":"soundcloud:tracks:1","title":"Song","media":{{"transcodings":[{}]}}}}"#,
+                transcodings.join(",")
+            );
+            return (200, body);
+        }
+        if let Some(quality) = path.strip_prefix("/media/") {
+            return (200, format!(r#"{{"url":"{url}/audio/{quality}"}}"#));
+        }
+        match path.strip_prefix("/audio/") {
+            Some(quality) => (200, format!("audio {quality}")),
+            None => (404, "{}".to_string()),
+        }
+    })
+    .await;
+    let dir = temp_dir("upgrade-order");
+    let client = server
+        .builder()
+        .with_download_archive(dir.join("archive.json"))
+        .with_redownload_on_upgrade(true)
+        .build()
+        .await
+        .unwrap();
+    let destination = dir.to_str().unwrap();
+
+    client
+        .download_track(TrackId(1), None, Some(destination), Some("song"))
+        .await
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dir.join("song.mp3")).unwrap(),
+        "audio sq"
+    );
+
+    // The new hq transcoding is listed after the sq one
+    qualities.lock().unwrap().push("hq");
+    client
+        .download_track(TrackId(1), None, Some(destination), Some("song"))
+        .await
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(dir.join("song.mp3")).unwrap(),
+        "audio hq"
+    );
+    let archive = DownloadArchive::open(dir.join("archive.json")).unwrap();
+    assert_eq!(archive.entries[0].quality.as_deref(), Some("hq"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{Autocompleter, response::Suggestion};
+use std::sync::Arc;
+use std::time::Duration;
+
+const SUGGESTIONS: &str = r#"{"collection":[
+    {"kind":"query","query":"daft punk","output":"<b>daft</b> punk"},
+    {"kind":"user","id":7,"output":"<b>Daft</b> Punk","permalink_url":"https://soundcloud.com/daftpunk"},
+    {"kind":"track","id":9,"output":"Around the World"}
+]}"#;
+
+async fn client_for(server: &StandInServer) -> Arc<soundcloud_rs::Client> {
+    let client = server.client().await;
+    Arc::new(client)
+}
+
+#[test]
+fn test_suggestion_highlights() {
+    let suggestion = Suggestion {
+        output: Some("<b>dé</b>ft <b>p</b>unk".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(suggestion.text(), "déft punk");
+    assert_eq!(suggestion.highlights(), vec![0..2, 5..6]);
+}
+
+#[tokio::test]
+async fn test_autocomplete_splits_queries_and_entities() {
+    let server = StandInServer::start(|_| (200, SUGGESTIONS.to_string())).await;
+    let client = client_for(&server).await;
+
+    let autocompleter = Autocompleter::new(client, Duration::ZERO);
+    let result = autocompleter.suggest("daft").await.unwrap().unwrap();
+    assert_eq!(result.queries.len(), 1);
+    assert_eq!(result.queries[0].text(), "daft punk");
+    let ids: Vec<i64> = result.entities.iter().filter_map(|s| s.id).collect();
+    assert_eq!(ids, vec![7, 9]);
+    assert!(
+        server.requests()[0]
+            .target
+            .starts_with("/search/autocomplete?q=daft")
+    );
+}
+
+#[tokio::test]
+async fn test_autocompleter_debounces_and_cancels() {
+    let server = StandInServer::start(|_| (200, SUGGESTIONS.to_string())).await;
+    let client = client_for(&server).await;
+    tokio::time::pause();
+    let autocompleter = Autocompleter::new(client, Duration::from_millis(100));
+
+    let typed = |input: &'static str, delay: u64| {
+        let autocompleter = autocompleter.clone();
+        async move {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            autocompleter.suggest(input).await.unwrap()
+        }
+    };
+    let (a, da, daf) = tokio::join!(typed("d", 0), typed("da", 20), typed("daf", 40));
+    assert!(
+        a.is_none() && da.is_none(),
+        "Superseded input should be dropped"
+    );
+    assert!(daf.is_some());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1, "Only the latest input should be sent");
+    assert!(requests[0].target.contains("q=daf"));
+
+    let (cancelled, _) = tokio::join!(typed("daft", 0), async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        autocompleter.cancel();
+    });
+    assert!(cancelled.is_none());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_autocompleter_cancels_request_in_flight() {
+    let server = StandInServer::start_delayed(Duration::from_secs(5), |_, _| {
+        (200, SUGGESTIONS.to_string())
+    })
+    .await;
+    let client = client_for(&server).await;
+    tokio::time::pause();
+    let autocompleter = Autocompleter::new(client, Duration::ZERO);
+
+    let started = tokio::time::Instant::now();
+    let (result, _) = tokio::join!(autocompleter.suggest("daft"), async {
+        // Wait until the request reached the server and is waiting for its answer
+        while server.requests().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        autocompleter.cancel();
+    });
+
+    assert!(
+        result.unwrap().is_none(),
+        "The request in flight should be dropped"
+    );
+    assert!(
+        started.elapsed() < Duration::from_secs(5),
+        "Cancelling should not wait for the response"
+    );
+}
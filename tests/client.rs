@@ -1,3 +1,6 @@
+// Baseline tests against the live API, kept in their original form
+#![allow(clippy::collapsible_if)]
+
 use soundcloud_rs::{
     Client, Identifier,
     query::{
@@ -25,18 +28,18 @@ async fn get_test_track_id(client: &Client) -> i64 {
         .expect("Failed to search tracks");
     let track = tracks.collection.first()
         .expect("No tracks found in search");
-    track.id.expect("Track has no ID")
+    track.id.expect("Track has no ID").0
 }
 
 // Helper function to get a test user ID from a track's user
 async fn get_test_user_id(client: &Client) -> i64 {
     let track_id = get_test_track_id(client).await;
-    let identifier = Identifier::Id(track_id);
+    let identifier = Identifier::Id(track_id).unchecked();
     let track = client.get_track(&identifier).await
         .expect("Failed to get track");
     track.user
         .and_then(|u| u.id)
-        .expect("Track has no user ID")
+        .expect("Track has no user ID").0
 }
 
 // Helper function to get a test playlist ID by searching for playlists
@@ -48,7 +51,7 @@ async fn get_test_playlist_id(client: &Client) -> Option<i64> {
     };
     if let Ok(playlists) = client.search_playlists(Some(&query)).await {
         if let Some(playlist) = playlists.collection.first() {
-            return playlist.id.map(|id| id as i64);
+            return playlist.id.map(|id| id.0);
         }
     }
     None
@@ -95,7 +98,7 @@ async fn test_get_track() {
     let track_id = get_test_track_id(&client).await;
     
     // Test with ID
-    let identifier = Identifier::Id(track_id);
+    let identifier = Identifier::Id(track_id).unchecked();
     let result = client.get_track(&identifier).await;
     assert!(result.is_ok(), "get_track should succeed");
     let track = result.unwrap();
@@ -103,7 +106,7 @@ async fn test_get_track() {
     
     // Test with URN if available
     if let Some(urn) = track.urn.clone() {
-        let urn_identifier = Identifier::Urn(urn);
+        let urn_identifier = Identifier::Urn(urn).unchecked();
         let result = client.get_track(&urn_identifier).await;
         assert!(result.is_ok(), "get_track with URN should succeed");
     }
@@ -113,7 +116,7 @@ async fn test_get_track() {
 async fn test_get_track_related() {
     let client = get_client().await;
     let track_id = get_test_track_id(&client).await;
-    let identifier = Identifier::Id(track_id);
+    let identifier = Identifier::Id(track_id).unchecked();
     
     // Test without pagination
     let result = client.get_track_related(&identifier, None).await;
@@ -133,7 +136,7 @@ async fn test_get_track_related() {
 async fn test_get_stream_url() {
     let client = get_client().await;
     let track_id = get_test_track_id(&client).await;
-    let identifier = Identifier::Id(track_id);
+    let identifier = Identifier::Id(track_id).unchecked();
     
     // Test with default stream type (Progressive)
     let result = client.get_stream_url(&identifier, None).await;
@@ -151,7 +154,7 @@ async fn test_get_stream_url() {
 async fn test_get_track_waveform() {
     let client = get_client().await;
     let track_id = get_test_track_id(&client).await;
-    let identifier = Identifier::Id(track_id);
+    let identifier = Identifier::Id(track_id).unchecked();
     
     let result = client.get_track_waveform(&identifier).await;
     assert!(result.is_ok(), "get_track_waveform should succeed");
@@ -184,7 +187,7 @@ async fn test_get_user() {
     let user_id = get_test_user_id(&client).await;
     
     // Test with ID
-    let identifier = Identifier::Id(user_id);
+    let identifier = Identifier::Id(user_id).unchecked();
     let result = client.get_user(&identifier).await;
     assert!(result.is_ok(), "get_user should succeed");
     let user = result.unwrap();
@@ -192,7 +195,7 @@ async fn test_get_user() {
     
     // Test with URN if available
     if let Some(urn) = user.urn.clone() {
-        let urn_identifier = Identifier::Urn(urn);
+        let urn_identifier = Identifier::Urn(urn).unchecked();
         let result = client.get_user(&urn_identifier).await;
         assert!(result.is_ok(), "get_user with URN should succeed");
     }
@@ -202,7 +205,7 @@ async fn test_get_user() {
 async fn test_get_user_followers() {
     let client = get_client().await;
     let user_id = get_test_user_id(&client).await;
-    let identifier = Identifier::Id(user_id);
+    let identifier = Identifier::Id(user_id).unchecked();
     
     // Test without pagination
     let result = client.get_user_followers(&identifier, None).await;
@@ -222,7 +225,7 @@ async fn test_get_user_followers() {
 async fn test_get_user_followings() {
     let client = get_client().await;
     let user_id = get_test_user_id(&client).await;
-    let identifier = Identifier::Id(user_id);
+    let identifier = Identifier::Id(user_id).unchecked();
     
     // Test without pagination
     let result = client.get_user_followings(&identifier, None).await;
@@ -242,7 +245,7 @@ async fn test_get_user_followings() {
 async fn test_get_user_playlists() {
     let client = get_client().await;
     let user_id = get_test_user_id(&client).await;
-    let identifier = Identifier::Id(user_id);
+    let identifier = Identifier::Id(user_id).unchecked();
     
     // Test without pagination
     let result = client.get_user_playlists(&identifier, None).await;
@@ -262,7 +265,7 @@ async fn test_get_user_playlists() {
 async fn test_get_user_tracks() {
     let client = get_client().await;
     let user_id = get_test_user_id(&client).await;
-    let identifier = Identifier::Id(user_id);
+    let identifier = Identifier::Id(user_id).unchecked();
     
     // Test without pagination
     let result = client.get_user_tracks(&identifier, None).await;
@@ -282,7 +285,7 @@ async fn test_get_user_tracks() {
 async fn test_get_user_reposts() {
     let client = get_client().await;
     let user_id = get_test_user_id(&client).await;
-    let identifier = Identifier::Id(user_id);
+    let identifier = Identifier::Id(user_id).unchecked();
     
     // Test without pagination
     let result = client.get_user_reposts(&identifier, None).await;
@@ -320,13 +323,13 @@ async fn test_get_playlist() {
     
     // Try to get a playlist from search or user's playlists
     let user_id = get_test_user_id(&client).await;
-    let user_identifier = Identifier::Id(user_id);
+    let user_identifier = Identifier::Id(user_id).unchecked();
     
     // First try to get a playlist from the user's playlists
     if let Ok(playlists) = client.get_user_playlists(&user_identifier, Some(&Paging { limit: Some(1), ..Default::default() })).await {
         if let Some(playlist) = playlists.collection.first() {
             if let Some(playlist_id) = playlist.id {
-                let identifier = Identifier::Id(playlist_id as i64);
+                let identifier = Identifier::Id(playlist_id.0).unchecked();
                 let result = client.get_playlist(&identifier).await;
                 assert!(result.is_ok(), "get_playlist should succeed");
                 let playlist = result.unwrap();
@@ -338,7 +341,7 @@ async fn test_get_playlist() {
     
     // Fallback: try to get a playlist from search
     if let Some(playlist_id) = get_test_playlist_id(&client).await {
-        let identifier = Identifier::Id(playlist_id);
+        let identifier = Identifier::Id(playlist_id).unchecked();
         let result = client.get_playlist(&identifier).await;
         assert!(result.is_ok(), "get_playlist should succeed");
     }
@@ -350,12 +353,12 @@ async fn test_get_playlist_reposters() {
     
     // Try to get a playlist from a user's playlists first
     let user_id = get_test_user_id(&client).await;
-    let user_identifier = Identifier::Id(user_id);
+    let user_identifier = Identifier::Id(user_id).unchecked();
     
     if let Ok(playlists) = client.get_user_playlists(&user_identifier, Some(&Paging { limit: Some(1), ..Default::default() })).await {
         if let Some(playlist) = playlists.collection.first() {
             if let Some(playlist_id) = playlist.id {
-                let identifier = Identifier::Id(playlist_id as i64);
+                let identifier = Identifier::Id(playlist_id.0).unchecked();
                 
                 // Test without pagination
                 let result = client.get_playlist_reposters(&identifier, None).await;
@@ -376,7 +379,7 @@ async fn test_get_playlist_reposters() {
     
     // Fallback: try to get a playlist from search
     if let Some(playlist_id) = get_test_playlist_id(&client).await {
-        let identifier = Identifier::Id(playlist_id);
+        let identifier = Identifier::Id(playlist_id).unchecked();
         let result = client.get_playlist_reposters(&identifier, None).await;
         assert!(result.is_ok(), "get_playlist_reposters should succeed");
     }
@@ -0,0 +1,104 @@
+use soundcloud_rs::{
+    CommentCue, CommentExportOptions, CommentFileFormat,
+    response::{Comment, Track, UserSummary},
+};
+use std::path::Path;
+
+fn comment(timestamp: i64, author: &str, body: &str) -> Comment {
+    Comment {
+        body: Some(body.to_string()),
+        timestamp: Some(timestamp),
+        user: Some(UserSummary {
+            username: Some(author.to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn track() -> Track {
+    Track {
+        title: Some("Master".to_string()),
+        duration: Some(62_000),
+        user: Some(UserSummary {
+            username: Some("Label".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn comments() -> Vec<Comment> {
+    vec![
+        comment(61_000, "c", "end <3"),
+        comment(1_500, "a", "great\nintro"),
+        comment(3_000, "b", "agreed"),
+        Comment {
+            body: Some("untimed".to_string()),
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+fn test_srt_and_vtt_are_aligned_and_sorted() {
+    let options = CommentExportOptions::default();
+    let srt = CommentFileFormat::Srt.render(&track(), &comments(), &options);
+    assert_eq!(
+        srt,
+        "1\n00:00:01,500 --> 00:00:05,500\na: great intro\n\n\
+         2\n00:00:03,000 --> 00:00:07,000\nb: agreed\n\n\
+         3\n00:01:01,000 --> 00:01:02,000\nc: end <3\n\n"
+    );
+
+    let vtt = CommentFileFormat::WebVtt.render(&track(), &comments(), &options);
+    assert!(vtt.starts_with("WEBVTT\n\n00:00:01.500 --> 00:00:05.500\n"));
+    assert!(vtt.contains("c: end &lt;3"));
+}
+
+#[test]
+fn test_merge_overlapping_and_line_cap() {
+    let mut all = comments();
+    all.push(comment(4_000, "d", "same"));
+    let options = CommentExportOptions {
+        merge_overlapping: true,
+        max_lines: Some(2),
+        include_author: false,
+        ..Default::default()
+    };
+    let cues = CommentCue::from_comments(&all, &options, Some(62_000));
+    assert_eq!(
+        cues,
+        vec![
+            CommentCue {
+                start: 1_500,
+                end: 8_000,
+                lines: vec!["great intro".to_string(), "agreed (+1 more)".to_string()],
+            },
+            CommentCue {
+                start: 61_000,
+                end: 62_000,
+                lines: vec!["end <3".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_lrc_has_header_and_one_line_per_cue() {
+    let options = CommentExportOptions {
+        merge_overlapping: true,
+        ..Default::default()
+    };
+    let lrc = CommentFileFormat::Lrc.render(&track(), &comments(), &options);
+    assert_eq!(
+        lrc,
+        "[ti:Master]\n[ar:Label]\n[length:01:02.00]\n\
+         [00:01.50]a: great intro / b: agreed\n\
+         [01:01.00]c: end <3\n"
+    );
+    assert_eq!(
+        CommentFileFormat::Lrc.path_for(Path::new("out/Master.mp3")),
+        Path::new("out/Master.lrc")
+    );
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::StandInServer;
+use futures::TryStreamExt;
+use soundcloud_rs::{CommentsQuery, TrackId, response::Comment};
+
+async fn start_server() -> StandInServer {
+    StandInServer::start_linked(|url, req| {
+        if req.target.contains("cursor=2") {
+            return (
+                200,
+                r#"{"collection":[{"id":3,"body":"reply","timestamp":30000,"parent_comment_id":1}],"next_href":null}"#
+                    .to_string(),
+            );
+        }
+        let body = format!(
+            r#"{{"collection":[{{"id":1,"body":"great intro","timestamp":1500,"user":{{"id":8,"username":"fan"}},"reply_count":1}},{{"id":2,"body":"drop!","timestamp":61000}}],"next_href":"{}/tracks/5/comments?cursor=2"}}"#,
+            url
+        );
+        (200, body)
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_get_track_comments_page() {
+    let server = start_server().await;
+    let client = server.client().await;
+
+    let query = CommentsQuery {
+        sort: Some("timestamp".to_string()),
+        threaded: Some(true),
+        limit: Some(2),
+        ..Default::default()
+    };
+    let page = client
+        .get_track_comments(TrackId(5), Some(&query))
+        .await
+        .unwrap();
+    assert_eq!(page.collection.len(), 2);
+    let first = &page.collection[0];
+    assert_eq!(first.timestamp, Some(1500));
+    assert_eq!(first.reply_count, Some(1));
+    assert!(!first.is_reply());
+    assert_eq!(
+        first.user.as_ref().and_then(|u| u.username.as_deref()),
+        Some("fan")
+    );
+
+    let target = &server.requests()[0].target;
+    assert!(target.starts_with("/tracks/5/comments?"));
+    assert!(target.contains("sort=timestamp"));
+    assert!(target.contains("threaded=true"));
+}
+
+#[tokio::test]
+async fn test_stream_track_comments_follows_pages() {
+    let server = start_server().await;
+    let client = server.client().await;
+
+    let comments: Vec<Comment> = client
+        .stream_track_comments(TrackId(5), None)
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<i64> = comments.iter().filter_map(|c| c.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert!(comments[2].is_reply());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].target.contains("linked_partitioning=true"));
+}
@@ -0,0 +1,197 @@
+#![allow(dead_code)]
+
+use soundcloud_rs::{Client, ClientBuilder};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// A request received by the [`StandInServer`]
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// The target without its query string.
+    pub fn path(&self) -> &str {
+        self.target.split('?').next().unwrap_or_default()
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Minimal local HTTP server standing in for the SoundCloud API in tests.
+/// Every request is recorded and answered by `respond` with a status and JSON body.
+pub struct StandInServer {
+    pub url: String,
+    pub requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl StandInServer {
+    pub async fn start<F>(respond: F) -> Self
+    where
+        F: Fn(&RecordedRequest) -> (u16, String) + Send + Sync + 'static,
+    {
+        Self::start_linked(move |_, request| respond(request)).await
+    }
+
+    /// Like [`StandInServer::start`], but `respond` also gets the server's own URL, for
+    /// answers that link back to it such as `next_href`.
+    pub async fn start_linked<F>(respond: F) -> Self
+    where
+        F: Fn(&str, &RecordedRequest) -> (u16, String) + Send + Sync + 'static,
+    {
+        Self::start_delayed(Duration::ZERO, respond).await
+    }
+
+    /// Like [`StandInServer::start_linked`], but every response is held back for `delay`
+    /// after the request was recorded.
+    pub async fn start_delayed<F>(delay: Duration, respond: F) -> Self
+    where
+        F: Fn(&str, &RecordedRequest) -> (u16, String) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let respond = Arc::new(respond);
+        let base_url = Arc::new(url.clone());
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let recorded = recorded.clone();
+                let respond = respond.clone();
+                let base_url = base_url.clone();
+                tokio::spawn(async move {
+                    let Some(request) = read_request(&mut stream).await else {
+                        return;
+                    };
+                    let (status, body) = respond(&base_url, &request);
+                    recorded.lock().unwrap().push(request);
+                    if !delay.is_zero() {
+                        tokio::time::sleep(delay).await;
+                    }
+                    let response = format!(
+                        "HTTP/1.1 {status} Stand-In\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Request targets without their query strings, in the order they arrived.
+    pub fn paths(&self) -> Vec<String> {
+        self.requests()
+            .iter()
+            .map(|r| r.path().to_string())
+            .collect()
+    }
+
+    /// A [`ClientBuilder`] with a fixed `client_id` that sends API requests to this server.
+    pub fn builder(&self) -> ClientBuilder {
+        ClientBuilder::new()
+            .with_client_id("test")
+            .with_api_url(self.url.clone())
+    }
+
+    /// A client built from [`StandInServer::builder`].
+    pub async fn client(&self) -> Client {
+        self.builder().build().await.unwrap()
+    }
+}
+
+/// Answer `req` from a small track catalog: `/tracks/{id}` is a track with one progressive
+/// transcoding of `quality`, which resolves to audio served at `/audio/{id}`. Returns `None`
+/// for other paths.
+pub fn catalog(url: &str, req: &RecordedRequest, quality: &str) -> Option<(u16, String)> {
+    let path = req.path();
+    if let Some(id) = path.strip_prefix("/tracks/") {
+        return Some((200, track_json(url, id, quality)));
+    }
+    if let Some(id) = path.strip_prefix("/media/") {
+        return Some((200, format!(r#"{{"url":"{url}/audio/{id}"}}"#)));
+    }
+    let id = path.strip_prefix("/audio/")?;
+    Some((200, format!("audio {id} {quality}")))
+}
+
+/// A catalog track as returned by [`catalog`].
+pub fn track_json(url: &str, id: &str, quality: &str) -> String {
+    format!(
+        r#"{{"id":{id},"urn":"soundcloud:tracks:{id}","title":"Song {id}","permalink_url":"https://soundcloud.com/artist/song-{id}","media":{{"transcodings":[{{"url":"{url}/media/{id}","preset":"mp3_0_0","quality":"{quality}","format":{{"protocol":"progressive","mime_type":"audio/mpeg"}}}}]}}}}"#
+    )
+}
+
+/// A fresh, empty directory under the system temp dir.
+pub fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("soundcloud-rs-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+async fn read_request(stream: &mut tokio::net::TcpStream) -> Option<RecordedRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let target = request_line.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect();
+    let content_length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    let body = String::from_utf8_lossy(&buf[header_end..]).to_string();
+
+    Some(RecordedRequest {
+        method,
+        target,
+        headers,
+        body,
+    })
+}
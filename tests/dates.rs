@@ -0,0 +1,77 @@
+use soundcloud_rs::response::{Playlist, Track};
+use std::time::Duration;
+
+fn track() -> Track {
+    serde_json::from_str(
+        r#"{
+            "created_at": "2024-01-31T12:30:00Z",
+            "last_modified": "2019/05/02 08:00:00 +0200",
+            "duration": 215000,
+            "release_year": 2023,
+            "release_month": 11,
+            "release_day": 3
+        }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_durations() {
+    assert_eq!(
+        track().playback_duration(),
+        Some(Duration::from_millis(215000))
+    );
+    let playlist: Playlist = serde_json::from_str(r#"{"duration":-1}"#).unwrap();
+    assert_eq!(playlist.playback_duration(), None);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_timestamps() {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    let track = track();
+    let created: DateTime<Utc> = track.created_at_as().unwrap();
+    assert_eq!(created.to_rfc3339(), "2024-01-31T12:30:00+00:00");
+    let modified: DateTime<Utc> = track.last_modified_as().unwrap();
+    assert_eq!(modified.to_rfc3339(), "2019-05-02T06:00:00+00:00");
+    assert_eq!(
+        track.release_date::<NaiveDate>(),
+        NaiveDate::from_ymd_opt(2023, 11, 3)
+    );
+
+    let partial: Track =
+        serde_json::from_str(r#"{"release_year":2023,"release_month":0}"#).unwrap();
+    assert_eq!(partial.release_date::<NaiveDate>(), None);
+    assert_eq!(partial.created_at_as::<DateTime<Utc>>(), None);
+
+    let release: Track = serde_json::from_str(r#"{"release":"2021-06-18T00:00:00Z"}"#).unwrap();
+    assert_eq!(
+        release.release_date::<NaiveDate>(),
+        NaiveDate::from_ymd_opt(2021, 6, 18)
+    );
+    let catalog: Track = serde_json::from_str(r#"{"release":"CAT-042"}"#).unwrap();
+    assert_eq!(catalog.release_date::<NaiveDate>(), None);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_timestamps() {
+    use time::{Date, Month, OffsetDateTime};
+
+    let track = track();
+    let created: OffsetDateTime = track.created_at_as().unwrap();
+    assert_eq!(created.unix_timestamp(), 1706704200);
+    let modified: OffsetDateTime = track.last_modified_as().unwrap();
+    assert_eq!(modified.offset().whole_hours(), 2);
+    assert_eq!(
+        track.release_date::<Date>(),
+        Date::from_calendar_date(2023, Month::November, 3).ok()
+    );
+
+    let release: Playlist = serde_json::from_str(r#"{"release":"2021-06-18"}"#).unwrap();
+    assert_eq!(
+        release.release_date::<Date>(),
+        Date::from_calendar_date(2021, Month::June, 18).ok()
+    );
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{ChartsQuery, TrackId, response::SelectionItem};
+
+#[tokio::test]
+async fn test_charts_page_and_next_page() {
+    let server = StandInServer::start_linked(|url, req| {
+        if req.target.contains("offset=20") {
+            return (
+                200,
+                r#"{"collection":[{"track":{"id":3},"score":10.0}]}"#.to_string(),
+            );
+        }
+        let body = format!(
+            r#"{{"kind":"trending","genre":{{"urn":"soundcloud:genres:techno","title":"Techno"}},"last_updated":"2026-10-12T00:00:00Z","collection":[{{"track":{{"id":1,"title":"Hot"}},"score":99.5}}],"next_href":"{}/charts?offset=20"}}"#,
+            url
+        );
+        (200, body)
+    })
+    .await;
+    let client = server.client().await;
+
+    let query = ChartsQuery {
+        kind: Some("trending".to_string()),
+        genre: Some("soundcloud:genres:techno".to_string()),
+        region: Some("soundcloud:regions:DE".to_string()),
+        ..Default::default()
+    };
+    let chart = client.get_charts(Some(&query)).await.unwrap();
+    assert_eq!(chart.kind.as_deref(), Some("trending"));
+    assert_eq!(
+        chart.genre.as_ref().and_then(|g| g.title.as_deref()),
+        Some("Techno")
+    );
+    let entry = &chart.collection[0];
+    assert_eq!(entry.score, Some(99.5));
+    assert_eq!(entry.track.as_ref().unwrap().title.as_deref(), Some("Hot"));
+
+    let target = &server.requests()[0].target;
+    assert!(target.starts_with("/charts?"));
+    assert!(target.contains("genre=soundcloud%3Agenres%3Atechno"));
+    assert!(target.contains("region=soundcloud%3Aregions%3ADE"));
+
+    let next_page = client.get_next_chart_page(&chart).await.unwrap().unwrap();
+    assert_eq!(
+        next_page.collection[0].track.as_ref().unwrap().id,
+        Some(TrackId(3))
+    );
+}
+
+#[tokio::test]
+async fn test_mixed_selections() {
+    let server = StandInServer::start(|_| {
+        (
+            200,
+            r#"{"collection":[{"kind":"selection","id":"chill","title":"Chill","items":{"collection":[
+                {"kind":"playlist","id":1,"title":"Evening"},
+                {"kind":"track","id":2},
+                {"kind":"system-playlist","id":"soundcloud:system-playlists:1"}
+            ]}}]}"#
+                .to_string(),
+        )
+    })
+    .await;
+    let client = server.client().await;
+
+    let selections = client.get_mixed_selections(None).await.unwrap();
+    let selection = &selections.collection[0];
+    assert_eq!(selection.title.as_deref(), Some("Chill"));
+    let items = &selection.items.as_ref().unwrap().collection;
+    assert!(
+        matches!(&items[0], SelectionItem::Playlist(p) if p.title.as_deref() == Some("Evening"))
+    );
+    assert!(matches!(&items[1], SelectionItem::Track(t) if t.id == Some(TrackId(2))));
+    assert!(
+        matches!(&items[2], SelectionItem::SystemPlaylist(p) if p.id.as_deref() == Some("soundcloud:system-playlists:1"))
+    );
+    assert!(
+        server.requests()[0]
+            .target
+            .starts_with("/mixed-selections?")
+    );
+}
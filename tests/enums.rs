@@ -0,0 +1,67 @@
+mod common;
+
+use common::StandInServer;
+use serde_json::json;
+use soundcloud_rs::{
+    Access, EmbeddableBy, License, PlaylistType, ResourceKind, Sharing,
+    query::TracksQuery,
+    response::{Playlist, Track},
+};
+
+#[test]
+fn test_response_enums() {
+    let track: Track = serde_json::from_str(
+        r#"{"kind":"track","access":"preview","sharing":"public","license":"cc-by-nc-sa",
+            "embeddable_by":"none"}"#,
+    )
+    .unwrap();
+    assert_eq!(track.kind, Some(ResourceKind::Track));
+    assert_eq!(track.access, Some(Access::Preview));
+    assert_eq!(track.sharing, Some(Sharing::Public));
+    assert_eq!(track.license, Some(License::CcByNcSa));
+    assert_eq!(track.embeddable_by, Some(EmbeddableBy::Nobody));
+
+    let playlist: Playlist =
+        serde_json::from_str(r#"{"playlist_type":"ep single","set_type":"mixtape"}"#).unwrap();
+    assert_eq!(playlist.playlist_type, Some(PlaylistType::EpSingle));
+    assert_eq!(
+        playlist.set_type,
+        Some(PlaylistType::Unknown("mixtape".to_string()))
+    );
+
+    let value = serde_json::to_value(&playlist).unwrap();
+    assert_eq!(value["playlist_type"], json!("ep single"));
+    assert_eq!(value["set_type"], json!("mixtape"));
+}
+
+#[test]
+fn test_unknown_values() {
+    let access: Access = "geoblocked".parse().unwrap();
+    assert_eq!(access, Access::Unknown("geoblocked".to_string()));
+    assert_eq!(access.to_string(), "geoblocked");
+    assert_eq!(Sharing::from("private"), Sharing::Private);
+
+    // Values are kept exactly as sent, so they serialize back unchanged
+    let kind = ResourceKind::from("EP single");
+    assert_eq!(kind, ResourceKind::Unknown("EP single".to_string()));
+    assert_eq!(serde_json::to_value(&kind).unwrap(), json!("EP single"));
+    assert_eq!(License::AllRightsReserved.as_str(), "all-rights-reserved");
+}
+
+#[tokio::test]
+async fn test_access_query() {
+    let server = StandInServer::start(|_| (200, r#"{"collection":[]}"#.to_string())).await;
+    let client = server.client().await;
+
+    let query = TracksQuery {
+        q: Some("house".to_string()),
+        access: Some(vec![Access::Playable, Access::Preview]),
+        ..Default::default()
+    };
+    client.search_tracks(Some(&query)).await.unwrap();
+    client.search_tracks(None).await.unwrap();
+
+    let requests = server.requests();
+    assert!(requests[0].target.contains("access=playable%2Cpreview"));
+    assert!(!requests[1].target.contains("access"));
+}
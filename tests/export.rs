@@ -0,0 +1,193 @@
+mod common;
+
+use common::{StandInServer, catalog, temp_dir};
+use serde_json::json;
+use soundcloud_rs::{
+    ArtistArchive, EXPORT_FORMAT_VERSION, EXPORT_INDEX_FILE_NAME, ExportIndex, ExportedPlaylist,
+    ExportedTrack, PlaylistId, TrackId, UserId,
+    response::{Playlist, Track, User},
+};
+use std::path::{Path, PathBuf};
+
+fn write(path: &Path, value: &impl serde::Serialize) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, serde_json::to_vec(value).unwrap()).unwrap();
+}
+
+#[test]
+fn test_archive_load_reads_model_types_in_order() {
+    let dir = std::env::temp_dir().join(format!("soundcloud-rs-{}-export", std::process::id()));
+
+    let user = User {
+        id: Some(UserId(1)),
+        username: Some("artist".to_string()),
+        ..Default::default()
+    };
+    write(&dir.join("profile.json"), &user);
+    for id in [20, 10] {
+        let track = Track {
+            id: Some(TrackId(id)),
+            ..Default::default()
+        };
+        write(&dir.join(format!("tracks/{id}/track.json")), &track);
+    }
+    let playlist = Playlist {
+        id: Some(PlaylistId(5)),
+        tracks: Some(vec![Track {
+            id: Some(TrackId(10)),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    write(&dir.join("playlists/5/playlist.json"), &playlist);
+    write(&dir.join("followers.json"), &vec![user.clone()]);
+
+    let index = ExportIndex {
+        format_version: EXPORT_FORMAT_VERSION,
+        user_id: Some(UserId(1)),
+        profile: PathBuf::from("profile.json"),
+        tracks: [20, 10]
+            .into_iter()
+            .map(|id| ExportedTrack {
+                id: TrackId(id),
+                metadata: PathBuf::from(format!("tracks/{id}/track.json")),
+                ..Default::default()
+            })
+            .collect(),
+        playlists: vec![ExportedPlaylist {
+            id: PlaylistId(5),
+            metadata: PathBuf::from("playlists/5/playlist.json"),
+            artwork: None,
+        }],
+        followers: Some(PathBuf::from("followers.json")),
+        ..Default::default()
+    };
+    write(&dir.join(EXPORT_INDEX_FILE_NAME), &index);
+
+    let archive = ArtistArchive::load(&dir).expect("Failed to load archive");
+    assert_eq!(archive.user.username.as_deref(), Some("artist"));
+    let ids: Vec<_> = archive.tracks.iter().filter_map(|t| t.id).collect();
+    assert_eq!(
+        ids,
+        vec![TrackId(20), TrackId(10)],
+        "Track order should follow the index"
+    );
+    assert_eq!(archive.playlists.len(), 1);
+    assert_eq!(archive.followers.len(), 1);
+    assert!(
+        archive.reposts.is_empty(),
+        "Missing sections should load as empty"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_export_user_archive() {
+    let server = StandInServer::start_linked(|url, req| {
+        let body = match req.path() {
+            "/users/1" => {
+                json!({"id": 1, "username": "artist", "avatar_url": format!("{url}/img/avatar")})
+            }
+            "/users/1/tracks" => json!({"collection": [
+                {"id": 10, "artwork_url": format!("{url}/img/10")},
+                {"id": 20, "artwork_url": format!("{url}/gone")},
+            ]}),
+            "/users/1/playlists" => json!({"collection": [{"id": 5, "title": "Mix"}]}),
+            "/playlists/5" => json!({"id": 5, "title": "Mix", "tracks": [{"id": 20}, {"id": 10}]}),
+            "/users/1/followers" => json!({"collection": [{"id": 2, "username": "fan"}]}),
+            "/users/1/followings" | "/stream/users/1/reposts" => json!({"collection": []}),
+            path if path.starts_with("/img/") => return (200, "jpeg".to_string()),
+            _ => return catalog(url, req, "sq").unwrap_or((404, "{}".to_string())),
+        };
+        (200, body.to_string())
+    })
+    .await;
+    let client = server.client().await;
+    let dir = temp_dir("export-user");
+
+    let index = client
+        .export_user_archive(UserId(1), dir.to_str().unwrap(), None)
+        .await
+        .expect("Failed to export archive");
+    assert_eq!(index.avatar, Some(PathBuf::from("avatar.jpg")));
+    assert_eq!(index.tracks.len(), 2);
+    assert_eq!(
+        index.tracks[0].audio,
+        Some(PathBuf::from("tracks/10/audio.mp3"))
+    );
+    assert_eq!(
+        index.tracks[0].artwork,
+        Some(PathBuf::from("tracks/10/artwork.jpg"))
+    );
+    assert_eq!(index.tracks[1].artwork, None);
+    assert_eq!(
+        index.failures,
+        vec!["track 20 artwork: HTTP 404".to_string()]
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.join("tracks/20/audio.mp3")).unwrap(),
+        "audio 20 sq"
+    );
+
+    let archive = ArtistArchive::load(&dir).expect("Failed to load archive");
+    assert_eq!(archive.user.username.as_deref(), Some("artist"));
+    let order: Vec<_> = archive.playlists[0]
+        .tracks
+        .iter()
+        .flatten()
+        .filter_map(|t| t.id)
+        .collect();
+    assert_eq!(order, vec![TrackId(20), TrackId(10)]);
+    assert_eq!(archive.followers.len(), 1);
+    assert!(archive.followings.is_empty());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_export_copies_archived_audio() {
+    let server = StandInServer::start_linked(|url, req| {
+        let body = match req.path() {
+            "/users/1" => json!({"id": 1, "username": "artist"}),
+            "/users/1/tracks" => json!({"collection": [{"id": 10}]}),
+            "/users/1/playlists"
+            | "/users/1/followers"
+            | "/users/1/followings"
+            | "/stream/users/1/reposts" => json!({"collection": []}),
+            _ => return catalog(url, req, "sq").unwrap_or((404, "{}".to_string())),
+        };
+        (200, body.to_string())
+    })
+    .await;
+    let dir = temp_dir("export-archived");
+    let client = server
+        .builder()
+        .with_download_archive(dir.join("archive.json"))
+        .build()
+        .await
+        .unwrap();
+
+    let downloads = dir.join("downloads");
+    client
+        .download_track(TrackId(10), None, downloads.to_str(), None)
+        .await
+        .unwrap();
+
+    let export = dir.join("export");
+    let index = client
+        .export_user_archive(UserId(1), export.to_str().unwrap(), None)
+        .await
+        .expect("Failed to export archive");
+    assert!(index.failures.is_empty());
+    assert_eq!(
+        index.tracks[0].audio,
+        Some(PathBuf::from("tracks/10/audio.mp3"))
+    );
+    assert_eq!(
+        std::fs::read_to_string(export.join("tracks/10/audio.mp3")).unwrap(),
+        "audio 10 sq"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
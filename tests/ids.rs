@@ -0,0 +1,82 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{
+    Identifier, PlaylistId, TrackId, Urn, UserId,
+    response::{Playlist, Track},
+};
+
+#[test]
+fn test_urn_parse_and_display() {
+    let urn: Urn = "soundcloud:tracks:123".parse().unwrap();
+    assert_eq!(urn.kind, "tracks");
+    assert_eq!(urn.numeric_id().unwrap(), 123);
+    assert_eq!(urn.to_string(), "soundcloud:tracks:123");
+    assert_eq!(TrackId::try_from(&urn).unwrap(), TrackId(123));
+    assert!(UserId::try_from(&urn).is_err());
+
+    assert!("tracks:123".parse::<Urn>().is_err());
+    assert!("soundcloud:tracks:".parse::<Urn>().is_err());
+    let system: Urn = "soundcloud:system-playlists:weekly:42".parse().unwrap();
+    assert_eq!(system.id, "weekly:42");
+    assert!(system.numeric_id().is_err());
+
+    assert_eq!(UserId(7).urn().to_string(), "soundcloud:users:7");
+    let urn: Urn = serde_json::from_str(r#""soundcloud:playlists:9""#).unwrap();
+    assert_eq!(PlaylistId::try_from(&urn).unwrap(), PlaylistId(9));
+    assert!(serde_json::from_str::<Urn>(r#""nope""#).is_err());
+}
+
+#[test]
+fn test_model_typed_ids() {
+    let track: Track = serde_json::from_str(r#"{"urn":"soundcloud:tracks:55"}"#).unwrap();
+    assert_eq!(track.track_id(), Some(TrackId(55)));
+    let playlist: Playlist = serde_json::from_str(r#"{"id":8}"#).unwrap();
+    assert_eq!(playlist.playlist_id(), Some(PlaylistId(8)));
+    assert_eq!(
+        Identifier::Urn("soundcloud:users:3".to_string())
+            .numeric_id(UserId::URN_KIND)
+            .unwrap(),
+        3
+    );
+    let track_urn = Identifier::Urn("soundcloud:tracks:3".to_string());
+    assert!(track_urn.numeric_id(UserId::URN_KIND).is_err());
+    assert!(UserId::try_from(&track_urn).is_err());
+    assert_eq!(TrackId::try_from(&track_urn).unwrap(), TrackId(3));
+    assert_eq!(UserId::try_from(&Identifier::Id(4)).unwrap(), UserId(4));
+}
+
+#[tokio::test]
+async fn test_typed_ids_in_requests() {
+    let server = StandInServer::start(|req| {
+        let body = if req.target.contains("reposts") {
+            r#"{"collection":[]}"#
+        } else {
+            r#"{"id":5}"#
+        };
+        (200, body.to_string())
+    })
+    .await;
+    let client = server.client().await;
+
+    let track = client.get_track(TrackId(5)).await.unwrap();
+    client.get_track(&track.track_id().unwrap()).await.unwrap();
+    client.get_user(UserId(6)).await.unwrap();
+    client.get_playlist(PlaylistId(7)).await.unwrap();
+    let urn: Urn = "soundcloud:users:8".parse().unwrap();
+    client
+        .get_user_reposts(Identifier::from(urn).unchecked(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.paths(),
+        [
+            "/tracks/5",
+            "/tracks/5",
+            "/users/6",
+            "/playlists/7",
+            "/stream/users/8/reposts"
+        ]
+    );
+}
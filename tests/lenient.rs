@@ -0,0 +1,166 @@
+mod common;
+
+use common::StandInServer;
+use serde_json::json;
+use soundcloud_rs::{
+    TrackId, UserId,
+    query::TracksQuery,
+    response::{SearchAllResult, SpotlightItem, Track},
+};
+
+const PAGE: &str = r#"{
+    "collection": [
+        {"id": 1, "title": "Fine", "waveform_style": "bars"},
+        {"id": 2, "title": "Odd", "duration": "long", "user": {"id": "x", "username": "dj"}}
+    ],
+    "next_href": null,
+    "query_urn": "soundcloud:search:abc"
+}"#;
+
+#[test]
+fn test_unknown_fields_are_kept() {
+    let track: Track =
+        serde_json::from_str(r#"{"id":1,"waveform_style":"bars","badges":{"pro":true}}"#).unwrap();
+    assert_eq!(track.id, Some(TrackId(1)));
+    assert_eq!(track.extra.get("waveform_style"), Some(&json!("bars")));
+    assert!(track.extra.warnings.is_empty());
+
+    let value = serde_json::to_value(&track).unwrap();
+    assert_eq!(value["badges"], json!({"pro": true}));
+    assert_eq!(value["id"], json!(1));
+}
+
+async fn client(lenient: bool) -> (StandInServer, soundcloud_rs::Client) {
+    let server = StandInServer::start(|req| {
+        let body = match req.path() {
+            "/users/3/spotlight" => {
+                r#"{"collection":[{"kind":"track","id":"seven","title":"Pinned"},{"kind":"playlist","id":8}]}"#
+            }
+            "/users/3/tracks" => r#"{"collection":[{"id":1},42]}"#,
+            "/charts" => {
+                r#"{"kind":"top","collection":[{"track":{"id":1},"score":2.0},{"track":{"id":"x","title":"Odd"},"score":1.0}]}"#
+            }
+            "/search" => {
+                r#"{"collection":[{"kind":"user","id":2},{"kind":"track","id":3,"duration":"long"}]}"#
+            }
+            _ => PAGE,
+        };
+        (200, body.to_string())
+    })
+    .await;
+    let client = server
+        .builder()
+        .with_lenient_parsing(lenient)
+        .build()
+        .await
+        .unwrap();
+    (server, client)
+}
+
+#[tokio::test]
+async fn test_strict_parsing_fails_whole_page() {
+    let (_server, client) = client(false).await;
+    assert!(client.search_tracks(None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_lenient_parsing_records_warnings() {
+    let (_server, client) = client(true).await;
+    let page = client.search_tracks(None).await.unwrap();
+    assert_eq!(page.collection.len(), 2);
+    assert_eq!(
+        page.extra.get("query_urn"),
+        Some(&json!("soundcloud:search:abc"))
+    );
+
+    let fine = &page.collection[0];
+    assert!(fine.extra.warnings.is_empty());
+    assert_eq!(fine.extra.get("waveform_style"), Some(&json!("bars")));
+
+    let odd = &page.collection[1];
+    assert_eq!(odd.title.as_deref(), Some("Odd"));
+    assert_eq!(odd.duration, None);
+    assert_eq!(odd.extra.warnings.len(), 1);
+    assert_eq!(odd.extra.warnings[0].path, "collection[1].duration");
+    assert_eq!(odd.extra.warnings[0].value, json!("long"));
+
+    let user = odd.user.as_ref().unwrap();
+    assert_eq!(user.username.as_deref(), Some("dj"));
+    assert_eq!(user.extra.warnings[0].path, "collection[1].user.id");
+}
+
+#[tokio::test]
+async fn test_lenient_parsing_drops_unparseable_items() {
+    let (_server, client) = client(true).await;
+    let tracks = client.get_user_tracks(UserId(3), None).await.unwrap();
+    assert_eq!(tracks.collection.len(), 1);
+    assert_eq!(tracks.extra.warnings.len(), 1);
+    assert_eq!(tracks.extra.warnings[0].path, "collection[1]");
+    assert_eq!(tracks.extra.warnings[0].value, json!(42));
+}
+
+#[tokio::test]
+async fn test_lenient_parsing_reaches_into_kinded_items() {
+    let (_server, client) = client(true).await;
+
+    let spotlight = client.get_user_spotlight(UserId(3), None).await.unwrap();
+    assert_eq!(spotlight.collection.len(), 2);
+    let SpotlightItem::Track(track) = &spotlight.collection[0] else {
+        panic!("Spotlight track should keep its kind");
+    };
+    assert_eq!(track.title.as_deref(), Some("Pinned"));
+    assert_eq!(track.extra.warnings[0].path, "collection[0].id");
+    assert_eq!(track.extra.warnings[0].value, json!("seven"));
+
+    let results = client.search_all(None).await.unwrap();
+    let SearchAllResult::Track(track) = &results.collection[1] else {
+        panic!("Search result should keep its kind");
+    };
+    assert_eq!(track.id, Some(TrackId(3)));
+    assert_eq!(track.extra.warnings[0].path, "collection[1].duration");
+}
+
+#[tokio::test]
+async fn test_lenient_parsing_recovers_chart_entries() {
+    let (_server, strict) = client(false).await;
+    assert!(strict.get_charts(None).await.is_err());
+
+    let (_server, lenient) = client(true).await;
+    let chart = lenient.get_charts(None).await.unwrap();
+    assert_eq!(chart.collection.len(), 2);
+    let track = chart.collection[1].track.as_ref().unwrap();
+    assert_eq!(track.title.as_deref(), Some("Odd"));
+    assert_eq!(track.extra.warnings[0].path, "collection[1].track.id");
+}
+
+#[tokio::test]
+async fn test_lenient_parsing_gives_up_on_too_many_bad_fields() {
+    let server = StandInServer::start(|req| {
+        let count = if req.target.contains("q=many") {
+            300
+        } else {
+            200
+        };
+        let items: Vec<_> = (0..count)
+            .map(|id| json!({"id": id, "duration": "long"}))
+            .collect();
+        (200, json!({ "collection": items }).to_string())
+    })
+    .await;
+    let client = server
+        .builder()
+        .with_lenient_parsing(true)
+        .build()
+        .await
+        .unwrap();
+
+    let page = client.search_tracks(None).await.unwrap();
+    assert_eq!(page.collection.len(), 200);
+    assert!(page.collection.iter().all(|t| t.extra.warnings.len() == 1));
+
+    let query = TracksQuery {
+        q: Some("many".to_string()),
+        ..Default::default()
+    };
+    assert!(client.search_tracks(Some(&query)).await.is_err());
+}
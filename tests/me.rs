@@ -0,0 +1,84 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{OAuthConfig, Token, query::Paging};
+
+fn token(access_token: &str) -> Token {
+    Token {
+        access_token: access_token.to_string(),
+        refresh_token: None,
+        token_type: None,
+        scope: None,
+        expires_at: None,
+    }
+}
+
+async fn start_server() -> StandInServer {
+    StandInServer::start(|req| {
+        let body = match req.path() {
+            "/me" if req.header("authorization") == Some("OAuth other") => r#"{"id":2}"#,
+            "/me" => r#"{"id":9,"username":"me"}"#,
+            "/me/play-history/tracks" => r#"{"collection":[{"played_at":1,"track_id":5}]}"#,
+            "/stream" => r#"{"collection":[{"type":"track-repost"}]}"#,
+            _ => r#"{"collection":[]}"#,
+        };
+        (200, body.to_string())
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_get_me_methods_look_up_the_user_once() {
+    let server = start_server().await;
+    let client = server
+        .builder()
+        .with_oauth(OAuthConfig::authorization_code(
+            "app",
+            None,
+            "http://127.0.0.1:1/callback",
+        ))
+        .with_token(token("abc"))
+        .build()
+        .await
+        .unwrap();
+    let paging = Paging {
+        limit: Some(10),
+        ..Default::default()
+    };
+
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.username.as_deref(), Some("me"));
+    client.get_me_liked_tracks(Some(&paging)).await.unwrap();
+    client.get_me_liked_playlists(None).await.unwrap();
+    client.get_me_followings(None).await.unwrap();
+    client.get_me_playlists(None).await.unwrap();
+    assert_eq!(
+        client.get_me_stream(None).await.unwrap().collection.len(),
+        1
+    );
+    let history = client.get_me_play_history(None).await.unwrap();
+    assert_eq!(history.collection.len(), 1);
+
+    assert_eq!(
+        server.paths(),
+        vec![
+            "/me",
+            "/users/9/track_likes",
+            "/users/9/playlist_likes",
+            "/users/9/followings",
+            "/users/9/playlists",
+            "/stream",
+            "/me/play-history/tracks",
+        ]
+    );
+    assert!(server.requests()[1].target.contains("limit=10"));
+    for request in server.requests() {
+        assert_eq!(request.header("authorization"), Some("OAuth abc"));
+    }
+
+    // A new token may belong to another user
+    client.set_token(Some(token("other"))).await.unwrap();
+    client.get_me_playlists(None).await.unwrap();
+    let paths = server.paths();
+    assert_eq!(paths[paths.len() - 2..], ["/me", "/users/2/playlists"]);
+}
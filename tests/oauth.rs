@@ -0,0 +1,210 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{OAuthConfig, OAuthFlow, PkceChallenge, Token, TrackId, UserId};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[test]
+fn test_pkce_challenge_matches_rfc_7636() {
+    let pkce = PkceChallenge::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+    assert_eq!(pkce.challenge, "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+
+    let random = PkceChallenge::new();
+    assert!(random.verifier.len() >= 43, "Verifier must be at least 43 characters");
+}
+
+#[test]
+fn test_authorization_url_contains_pkce_parameters() {
+    let config = OAuthConfig::authorization_code("app", None, "http://127.0.0.1:8080/callback");
+    assert_eq!(config.flow, OAuthFlow::AuthorizationCode);
+    let pkce = PkceChallenge::from_verifier("verifier");
+    let url = config
+        .authorization_url(&pkce, "xyz")
+        .expect("Failed to build authorization URL");
+
+    assert!(url.starts_with("https://secure.soundcloud.com/authorize?"));
+    assert!(url.contains("client_id=app"));
+    assert!(url.contains("response_type=code"));
+    assert!(url.contains("code_challenge_method=S256"));
+    assert!(url.contains(&format!("code_challenge={}", pkce.challenge)));
+    assert!(url.contains("redirect_uri=http%3A%2F%2F127.0.0.1%3A8080%2Fcallback"));
+    assert!(url.contains("state=xyz"));
+}
+
+#[tokio::test]
+async fn test_client_credentials_token_request() {
+    let server = StandInServer::start(|_| {
+        (
+            200,
+            r#"{"access_token":"abc","refresh_token":"def","expires_in":3600,"token_type":"bearer"}"#
+                .to_string(),
+        )
+    })
+    .await;
+    let mut config = OAuthConfig::client_credentials("app", "secret");
+    config.token_url = format!("{}/oauth/token", server.url);
+
+    let token = config
+        .request_client_credentials()
+        .await
+        .expect("Token request should succeed");
+    assert_eq!(token.access_token, "abc");
+    assert_eq!(token.refresh_token.as_deref(), Some("def"));
+    assert!(!token.is_expiring(), "Fresh token should not be expiring");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/oauth/token");
+    // "app:secret" in base64
+    assert_eq!(requests[0].header("authorization"), Some("Basic YXBwOnNlY3JldA=="));
+    assert!(requests[0].body.contains("grant_type=client_credentials"));
+}
+
+#[tokio::test]
+async fn test_token_request_surfaces_errors() {
+    let server = StandInServer::start(|_| (401, r#"{"error":"invalid_client"}"#.to_string())).await;
+    let mut config = OAuthConfig::client_credentials("app", "wrong");
+    config.token_url = format!("{}/oauth/token", server.url);
+
+    let err = config
+        .request_client_credentials()
+        .await
+        .expect_err("Token request should fail");
+    assert!(err.to_string().contains("401"));
+}
+
+#[test]
+fn test_expiring_token() {
+    let token = Token {
+        access_token: "abc".to_string(),
+        refresh_token: None,
+        token_type: None,
+        scope: None,
+        expires_at: Some(0),
+    };
+    assert!(token.is_expiring());
+}
+
+const TOKEN_RESPONSE: &str = r#"{"access_token":"fresh","refresh_token":"next","expires_in":3600}"#;
+
+fn token(expires_in: u64) -> Token {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    Token {
+        access_token: "abc".to_string(),
+        refresh_token: Some("def".to_string()),
+        token_type: None,
+        scope: None,
+        expires_at: Some(now + expires_in),
+    }
+}
+
+fn config(server: &StandInServer) -> OAuthConfig {
+    let mut config = OAuthConfig::authorization_code("app", None, "http://127.0.0.1:1/callback");
+    config.token_url = format!("{}/oauth/token", server.url);
+    config
+}
+
+#[tokio::test]
+async fn test_token_only_sent_to_api_hosts() {
+    let cdn = StandInServer::start(|_| (200, r#"{"collection":[]}"#.to_string())).await;
+    let next_href = format!("{}/users/1/followers?cursor=2", cdn.url);
+    let api = StandInServer::start(move |req| match req.path() {
+        "/tracks/1" => (200, r#"{"id":1}"#.to_string()),
+        _ => (
+            200,
+            format!(r#"{{"collection":[{{"id":2}}],"next_href":"{next_href}"}}"#),
+        ),
+    })
+    .await;
+    let client = api
+        .builder()
+        .with_oauth(config(&api))
+        .with_token(token(3600))
+        .build()
+        .await
+        .unwrap();
+
+    client.get_track(TrackId(1)).await.unwrap();
+    let first = client.get_user_followers(UserId(1), None).await.unwrap();
+    let followers = client.collect_pages(first).await.unwrap();
+    assert_eq!(followers.len(), 1);
+
+    for request in api.requests() {
+        assert_eq!(request.header("authorization"), Some("OAuth abc"));
+    }
+    let cdn_requests = cdn.requests();
+    assert_eq!(cdn_requests.len(), 1);
+    assert_eq!(cdn_requests[0].header("authorization"), None);
+}
+
+#[tokio::test]
+async fn test_expiring_token_is_refreshed_before_request() {
+    let server = StandInServer::start(|req| match req.path() {
+        "/oauth/token" => (200, TOKEN_RESPONSE.to_string()),
+        _ => (200, r#"{"id":1}"#.to_string()),
+    })
+    .await;
+    let client = server
+        .builder()
+        .with_oauth(config(&server))
+        .with_token(token(10))
+        .build()
+        .await
+        .unwrap();
+
+    client.get_track(TrackId(1)).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(server.paths(), vec!["/oauth/token", "/tracks/1"]);
+    assert!(requests[0].body.contains("grant_type=refresh_token"));
+    assert!(requests[0].body.contains("refresh_token=def"));
+    assert_eq!(requests[1].header("authorization"), Some("OAuth fresh"));
+    let stored = client.get_token().await.unwrap();
+    assert_eq!(stored.refresh_token.as_deref(), Some("next"));
+}
+
+#[tokio::test]
+async fn test_pkce_loopback_listener() {
+    let server = StandInServer::start(|_| (200, TOKEN_RESPONSE.to_string())).await;
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let redirect_uri = format!("http://127.0.0.1:{port}/callback");
+    let mut config = OAuthConfig::authorization_code("app", None, redirect_uri);
+    config.token_url = format!("{}/oauth/token", server.url);
+    let client = server.builder().with_oauth(config).build().await.unwrap();
+
+    let token = client
+        .authorize_with_pkce(|url| {
+            let url = reqwest::Url::parse(url).unwrap();
+            let state = url
+                .query_pairs()
+                .find(|(k, _)| k == "state")
+                .map(|(_, v)| v.into_owned())
+                .unwrap();
+            let base = format!("http://127.0.0.1:{port}");
+            tokio::spawn(async move {
+                // Requests for other paths are ignored until the callback arrives
+                let _ = reqwest::get(format!("{base}/favicon.ico")).await;
+                reqwest::get(format!("{base}/callback?code=xyz&state={state}"))
+                    .await
+                    .unwrap();
+            });
+        })
+        .await
+        .expect("Authorization should succeed");
+
+    assert_eq!(token.access_token, "fresh");
+    assert_eq!(client.get_token().await.unwrap().access_token, "fresh");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].body.contains("grant_type=authorization_code"));
+    assert!(requests[0].body.contains("code=xyz"));
+    assert!(requests[0].body.contains("code_verifier="));
+}
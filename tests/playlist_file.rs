@@ -0,0 +1,98 @@
+mod common;
+
+use common::{StandInServer, catalog, temp_dir};
+use soundcloud_rs::{
+    PlaylistEntry, PlaylistFormat, PlaylistId, TrackId,
+    response::{Playlist, Track, UserSummary},
+};
+
+fn track(id: i64, title: &str) -> Track {
+    Track {
+        id: Some(TrackId(id)),
+        title: Some(title.to_string()),
+        duration: Some(185_500),
+        permalink_url: Some(format!("https://soundcloud.com/artist/{id}")),
+        user: Some(UserSummary {
+            username: Some("Artist".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn playlist() -> Playlist {
+    Playlist {
+        title: Some("Weekly & Co".to_string()),
+        tracks: Some(vec![track(1, "First"), track(2, "Second")]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_m3u8_keeps_order_and_durations() {
+    let playlist = playlist();
+    let entries = vec![
+        PlaylistEntry::from_track(&track(1, "First"), "First.mp3"),
+        PlaylistEntry::from_track(&track(2, "Second"), "Second.mp3"),
+    ];
+    let m3u8 = PlaylistFormat::M3u8.render(&playlist, &entries);
+    assert_eq!(
+        m3u8,
+        "#EXTM3U\n#PLAYLIST:Weekly & Co\n\
+         #EXTINF:185,Artist - First\nFirst.mp3\n\
+         #EXTINF:185,Artist - Second\nSecond.mp3\n"
+    );
+}
+
+#[test]
+fn test_pls_and_remote_entries() {
+    let pls = PlaylistFormat::Pls.render_remote(&playlist());
+    assert!(pls.starts_with("[playlist]\nFile1=https://soundcloud.com/artist/1\n"));
+    assert!(pls.contains("Title2=Artist - Second\nLength2=185\n"));
+    assert!(pls.ends_with("NumberOfEntries=2\nVersion=2\n"));
+}
+
+#[test]
+fn test_xspf_escapes_and_encodes_locations() {
+    let entries = vec![PlaylistEntry::from_track(&track(1, "A <B>"), "A B.mp3")];
+    let xspf = PlaylistFormat::Xspf.render(&playlist(), &entries);
+    assert!(xspf.contains("<title>Weekly &amp; Co</title>"));
+    assert!(xspf.contains("<location>A%20B.mp3</location>"));
+    assert!(xspf.contains("<title>A &lt;B&gt;</title>"));
+    assert!(xspf.contains("<duration>185500</duration>"));
+}
+
+#[tokio::test]
+async fn test_playlist_file_points_at_archived_tracks() {
+    let server = StandInServer::start_linked(|url, req| {
+        if req.path() == "/playlists/7" {
+            let body = r#"{"id":7,"title":"Mix","tracks":[{"id":3},{"id":4}]}"#;
+            return (200, body.to_string());
+        }
+        catalog(url, req, "sq").unwrap()
+    })
+    .await;
+    let dir = temp_dir("playlist-archived");
+    let client = server
+        .builder()
+        .with_download_archive(dir.join("archive.json"))
+        .with_playlist_file(PlaylistFormat::M3u8)
+        .build()
+        .await
+        .unwrap();
+
+    let elsewhere = dir.join("elsewhere");
+    client
+        .download_track(TrackId(3), None, elsewhere.to_str(), None)
+        .await
+        .unwrap();
+    let playlists = dir.join("playlists");
+    client
+        .download_playlist(PlaylistId(7), playlists.to_str(), None)
+        .await
+        .unwrap();
+
+    let m3u8 = std::fs::read_to_string(playlists.join("Mix/Mix.m3u8")).unwrap();
+    let locations: Vec<_> = m3u8.lines().filter(|l| !l.starts_with('#')).collect();
+    assert_eq!(locations, vec!["../../elsewhere/Song 3.mp3", "Song 4.mp3"]);
+}
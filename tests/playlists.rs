@@ -0,0 +1,153 @@
+mod common;
+
+use common::StandInServer;
+use serde_json::{Value, json};
+use soundcloud_rs::{PlaylistId, PlaylistParams, Sharing, TrackId};
+
+const PLAYLIST: &str = r#"{"id":10,"title":"Weekly","tracks":[{"id":1},{"id":2},{"id":3}]}"#;
+
+async fn start_server() -> StandInServer {
+    StandInServer::start(|req| match req.method.as_str() {
+        "GET" => (200, PLAYLIST.to_string()),
+        "DELETE" => (200, String::new()),
+        // Echo the submitted playlist back like the API does
+        _ => {
+            let body: Value = serde_json::from_str(&req.body).unwrap();
+            let tracks: Vec<Value> = body["playlist"]["tracks"]
+                .as_array()
+                .map(|ids| ids.iter().map(|id| json!({ "id": id })).collect())
+                .unwrap_or_default();
+            let mut playlist = body["playlist"].clone();
+            playlist["id"] = json!(10);
+            playlist["tracks"] = json!(tracks);
+            (200, playlist.to_string())
+        }
+    })
+    .await
+}
+
+fn sent_body(server: &StandInServer, method: &str) -> Value {
+    let request = server
+        .requests()
+        .into_iter()
+        .rfind(|r| r.method == method)
+        .expect("Missing request");
+    serde_json::from_str(&request.body).unwrap()
+}
+
+fn track_ids(playlist: &soundcloud_rs::Playlist) -> Vec<i64> {
+    playlist
+        .tracks
+        .as_ref()
+        .unwrap()
+        .iter()
+        .filter_map(|t| t.id.map(|id| id.0))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_create_and_update_playlist() {
+    let server = start_server().await;
+    let client = server.client().await;
+
+    let params = PlaylistParams {
+        title: Some("Weekly".to_string()),
+        sharing: Some(Sharing::Private),
+        tracks: Some(vec![TrackId(5), TrackId(6)]),
+        ..Default::default()
+    };
+    let playlist = client.create_playlist(&params).await.unwrap();
+    assert_eq!(playlist.id, Some(PlaylistId(10)));
+    assert_eq!(track_ids(&playlist), vec![5, 6]);
+    assert_eq!(
+        sent_body(&server, "POST"),
+        json!({ "playlist": { "title": "Weekly", "sharing": "private", "tracks": [5, 6] } })
+    );
+
+    let update = PlaylistParams {
+        description: Some("Fresh picks".to_string()),
+        ..Default::default()
+    };
+    let playlist = client
+        .update_playlist(PlaylistId(10), &update)
+        .await
+        .unwrap();
+    assert_eq!(playlist.description.as_deref(), Some("Fresh picks"));
+    // Unset fields are left out so they are not changed
+    assert_eq!(
+        sent_body(&server, "PUT"),
+        json!({ "playlist": { "description": "Fresh picks" } })
+    );
+    let put = server.requests().into_iter().rfind(|r| r.method == "PUT");
+    assert!(put.unwrap().target.starts_with("/playlists/10?"));
+}
+
+#[tokio::test]
+async fn test_edit_playlist_tracks() {
+    let server = start_server().await;
+    let client = server.client().await;
+    let id = PlaylistId(10);
+
+    let playlist = client
+        .add_playlist_tracks(&id, &[TrackId(4)])
+        .await
+        .unwrap();
+    assert_eq!(track_ids(&playlist), vec![1, 2, 3, 4]);
+
+    let playlist = client
+        .remove_playlist_tracks(&id, &[TrackId(2)])
+        .await
+        .unwrap();
+    assert_eq!(track_ids(&playlist), vec![1, 3]);
+
+    let playlist = client.move_playlist_track(&id, 2, 0).await.unwrap();
+    assert_eq!(track_ids(&playlist), vec![3, 1, 2]);
+
+    let playlist = client
+        .set_playlist_tracks(&id, &[TrackId(9), TrackId(8)])
+        .await
+        .unwrap();
+    assert_eq!(track_ids(&playlist), vec![9, 8]);
+
+    assert!(client.move_playlist_track(&id, 5, 0).await.is_err());
+
+    client.delete_playlist(&id).await.unwrap();
+    let last = server.requests().pop().unwrap();
+    assert_eq!(last.method, "DELETE");
+    assert!(last.target.starts_with("/playlists/10?"));
+}
+
+#[tokio::test]
+async fn test_edit_keeps_tracks_without_id() {
+    let server = StandInServer::start(|req| {
+        let body = if req.path() == "/playlists/11" {
+            r#"{"id":11,"tracks":[{"id":1},{"urn":"soundcloud:tracks:2"},{"title":"?"}]}"#
+        } else {
+            r#"{"id":10,"tracks":[{"id":1},{"urn":"soundcloud:tracks:2"}]}"#
+        };
+        (200, body.to_string())
+    })
+    .await;
+    let client = server.client().await;
+
+    client
+        .add_playlist_tracks(PlaylistId(10), &[TrackId(3)])
+        .await
+        .unwrap();
+    assert_eq!(
+        sent_body(&server, "PUT"),
+        json!({ "playlist": { "tracks": [1, 2, 3] } })
+    );
+
+    let err = client
+        .add_playlist_tracks(PlaylistId(11), &[TrackId(3)])
+        .await
+        .expect_err("A track without id or URN should fail the edit");
+    assert!(err.to_string().contains("position 2"));
+    let puts = server
+        .requests()
+        .iter()
+        .filter(|r| r.method == "PUT")
+        .count();
+    assert_eq!(puts, 1, "The partial list must not be written back");
+}
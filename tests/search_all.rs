@@ -0,0 +1,50 @@
+use soundcloud_rs::response::{SearchAllResponse, SearchAllResult};
+use soundcloud_rs::{PlaylistId, PlaylistType};
+
+const PAGE: &str = r#"{"collection":[
+    {"kind":"track","id":1,"title":"Song"},
+    {"kind":"user","id":2,"username":"artist"},
+    {"kind":"playlist","id":3,"title":"Mix","is_album":false},
+    {"kind":"playlist","id":4,"title":"LP","is_album":true,"set_type":"album"},
+    {"kind":"system-playlist","id":"soundcloud:system-playlists:weekly","title":"Weekly"},
+    {"kind":"podcast-episode","id":5}
+]}"#;
+
+#[test]
+fn test_unknown_kinds_do_not_break_the_page() {
+    let page: SearchAllResponse = serde_json::from_str(PAGE).expect("Page should parse");
+    let kinds: Vec<&str> = page.collection.iter().map(|r| r.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "track",
+            "user",
+            "playlist",
+            "playlist",
+            "system-playlist",
+            "podcast-episode"
+        ]
+    );
+    assert!(
+        matches!(&page.collection[3], SearchAllResult::Album(p) if p.set_type == Some(PlaylistType::Album))
+    );
+    assert!(matches!(&page.collection[5], SearchAllResult::Other(v) if v["id"] == 5));
+
+    // Kinds survive a round trip
+    let json = serde_json::to_string(&page.collection).unwrap();
+    let again: Vec<SearchAllResult> = serde_json::from_str(&json).unwrap();
+    assert!(matches!(&again[0], SearchAllResult::Track(t) if t.title.as_deref() == Some("Song")));
+    assert!(matches!(&again[3], SearchAllResult::Album(_)));
+}
+
+#[test]
+fn test_split_groups_by_kind() {
+    let page: SearchAllResponse = serde_json::from_str(PAGE).unwrap();
+    let groups = page.split();
+    assert_eq!(groups.tracks.len(), 1);
+    assert_eq!(groups.users[0].username.as_deref(), Some("artist"));
+    assert_eq!(groups.playlists[0].id, Some(PlaylistId(3)));
+    assert_eq!(groups.albums[0].id, Some(PlaylistId(4)));
+    assert_eq!(groups.system_playlists[0].title.as_deref(), Some("Weekly"));
+    assert_eq!(groups.other.len(), 1);
+}
@@ -0,0 +1,141 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{
+    CreatedAtFilter, DurationFilter, LicenseFilter, SearchSort, TrackFilters, TracksQuery,
+    ValueRange,
+};
+use std::time::Duration;
+
+#[test]
+fn test_filters_serialize_to_wire_format() {
+    let filters = TrackFilters {
+        bpm: Some(ValueRange::between(120, 128)),
+        duration: Some(DurationFilter::Range(ValueRange::at_most(
+            Duration::from_secs(300),
+        ))),
+        created_at: Some(CreatedAtFilter::LastWeek),
+        license: Some(LicenseFilter::ToUseCommercially),
+        genres: vec!["techno".to_string(), "house".to_string()],
+        sort: Some(SearchSort::Popular),
+        facet: Some("genre".to_string()),
+        ..Default::default()
+    };
+    let params: Vec<(String, String)> = filters.to_params();
+    let params: Vec<(&str, &str)> = params
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    assert_eq!(
+        params,
+        vec![
+            ("filter.bpm.from", "120"),
+            ("filter.bpm.to", "128"),
+            ("filter.duration.to", "300000"),
+            ("filter.created_at", "last_week"),
+            ("filter.license", "to_use_commercially"),
+            ("filter.genre", "techno,house"),
+            ("sort", "popular"),
+            ("facet", "genre"),
+        ]
+    );
+
+    let preset = TrackFilters {
+        duration: Some(DurationFilter::Epic),
+        ..Default::default()
+    };
+    assert_eq!(
+        preset.to_params(),
+        vec![("filter.duration".to_string(), "epic".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_search_sends_filters_and_reads_facets() {
+    let server = StandInServer::start(|_| {
+        (
+            200,
+            r#"{"collection":[{"id":1}],"facets":[{"name":"genre","facets":[{"value":"Techno","count":42,"filter":"techno"}]}]}"#
+                .to_string(),
+        )
+    })
+    .await;
+    let client = server.client().await;
+
+    let query = TracksQuery {
+        q: Some("acid".to_string()),
+        filters: Some(TrackFilters {
+            bpm: Some(ValueRange::at_least(130)),
+            facet: Some("genre".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let tracks = client.search_tracks(Some(&query)).await.unwrap();
+    let facet = &tracks.facets.as_ref().unwrap()[0];
+    assert_eq!(facet.name.as_deref(), Some("genre"));
+    let value = &facet.facets.as_ref().unwrap()[0];
+    assert_eq!(value.value.as_deref(), Some("Techno"));
+    assert_eq!(value.count, Some(42));
+
+    let target = &server.requests()[0].target;
+    assert!(target.contains("q=acid"));
+    assert!(target.contains("filter.bpm.from=130"));
+    assert!(target.contains("facet=genre"));
+    assert!(!target.contains("filter.bpm.to"));
+}
+
+#[test]
+fn test_filters_survive_a_round_trip() {
+    let query = TracksQuery {
+        q: Some("acid".to_string()),
+        filters: Some(TrackFilters {
+            bpm: Some(ValueRange::between(120, 128)),
+            duration: Some(DurationFilter::Range(ValueRange::at_least(
+                Duration::from_secs(60),
+            ))),
+            created_at: Some(CreatedAtFilter::LastYear),
+            license: Some(LicenseFilter::ToShare),
+            tags: vec!["acid".to_string(), "303".to_string()],
+            sort: Some(SearchSort::Newest),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&query).unwrap();
+    let parsed: TracksQuery = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.q.as_deref(), Some("acid"));
+    assert_eq!(parsed.filters, query.filters);
+
+    let plain: TracksQuery = serde_json::from_str(r#"{"q":"acid"}"#).unwrap();
+    assert_eq!(plain.filters, None);
+    assert!(serde_json::from_str::<TracksQuery>(r#"{"sort":"loudest"}"#).is_err());
+}
+
+#[tokio::test]
+async fn test_search_rejects_filters_set_twice() {
+    let server = StandInServer::start(|_| (200, r#"{"collection":[]}"#.to_string())).await;
+    let client = server.client().await;
+
+    let query = TracksQuery {
+        bpm: Some("120".to_string()),
+        filters: Some(TrackFilters {
+            bpm: Some(ValueRange::at_least(130)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let error = client.search_tracks(Some(&query)).await.unwrap_err();
+    assert!(error.to_string().contains("bpm"));
+    assert!(server.requests().is_empty());
+
+    let query = TracksQuery {
+        genres: Some("techno".to_string()),
+        filters: Some(TrackFilters {
+            bpm: Some(ValueRange::at_least(130)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    client.search_tracks(Some(&query)).await.unwrap();
+}
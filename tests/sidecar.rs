@@ -0,0 +1,75 @@
+mod common;
+
+use common::{StandInServer, catalog, temp_dir};
+use soundcloud_rs::{
+    SidecarFormat, TrackId, TrackSidecar,
+    response::{StreamType, Track, Transcoding},
+};
+use std::path::Path;
+
+#[test]
+fn test_sidecar_paths() {
+    let audio = Path::new("downloads/song.mp3");
+    assert_eq!(
+        SidecarFormat::Json.path_for(audio),
+        Path::new("downloads/song.json")
+    );
+    assert_eq!(
+        SidecarFormat::InfoJson.path_for(audio),
+        Path::new("downloads/song.info.json")
+    );
+}
+
+#[test]
+fn test_sidecar_roundtrip_yields_track() {
+    let path = std::env::temp_dir().join(format!(
+        "soundcloud-rs-{}-sidecar.info.json",
+        std::process::id()
+    ));
+    let sidecar = TrackSidecar {
+        track: Track {
+            id: Some(TrackId(42)),
+            title: Some("Song".to_string()),
+            ..Default::default()
+        },
+        transcoding: Transcoding {
+            preset: Some("mp3_0_0".to_string()),
+            ..Default::default()
+        },
+        stream_type: StreamType::Progressive,
+        downloaded_at: 1,
+        source_url: Some("https://soundcloud.com/artist/song".to_string()),
+    };
+    sidecar.save(&path).expect("Failed to save sidecar");
+
+    let track = TrackSidecar::load_track(&path).expect("Failed to load sidecar");
+    assert_eq!(track.id, Some(TrackId(42)));
+    assert_eq!(track.title.as_deref(), Some("Song"));
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[tokio::test]
+async fn test_sidecar_keeps_permalink_not_stream_url() {
+    let server = StandInServer::start_linked(|url, req| catalog(url, req, "sq").unwrap()).await;
+    let client = server
+        .builder()
+        .with_sidecar(SidecarFormat::Json)
+        .build()
+        .await
+        .unwrap();
+    let dir = temp_dir("sidecar-download");
+
+    client
+        .download_track(TrackId(3), None, dir.to_str(), Some("song"))
+        .await
+        .unwrap();
+
+    let text = std::fs::read_to_string(dir.join("song.json")).unwrap();
+    let sidecar: TrackSidecar = serde_json::from_str(&text).unwrap();
+    assert_eq!(
+        sidecar.source_url.as_deref(),
+        Some("https://soundcloud.com/artist/song-3")
+    );
+    assert!(!text.contains("/audio/3"), "Expiring stream URL was stored");
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::StandInServer;
+use futures::TryStreamExt;
+use soundcloud_rs::{Identifier, StationUrn, TrackId, response::Track};
+
+#[tokio::test]
+async fn test_track_and_artist_stations() {
+    let server = StandInServer::start_linked(|url, req| {
+        let path = req.path();
+        let body = if req.target.contains("cursor=2") {
+            r#"{"collection":[{"id":12}]}"#.to_string()
+        } else if path.ends_with("/tracks") {
+            format!(
+                r#"{{"collection":[{{"id":10}},{{"id":11}}],"next_href":"{}/stations/x/tracks?cursor=2"}}"#,
+                url
+            )
+        } else {
+            let urn = path.trim_start_matches("/stations/");
+            format!(
+                r#"{{"urn":"{urn}","type":"track-station","title":"Based on Song","tracks":{{"collection":[{{"id":10}}]}}}}"#
+            )
+        };
+        (200, body)
+    })
+    .await;
+    let client = server.client().await;
+
+    let station = client.get_track_station(TrackId(5)).await.unwrap();
+    assert_eq!(station.urn.as_deref(), Some("soundcloud:track-stations:5"));
+    assert_eq!(station.tracks.unwrap().collection[0].id, Some(TrackId(10)));
+
+    let station = client
+        .get_artist_station(Identifier::Urn("soundcloud:users:7".to_string()).unchecked())
+        .await
+        .unwrap();
+    assert_eq!(station.urn.as_deref(), Some("soundcloud:artist-stations:7"));
+    let wrong_kind = Identifier::Urn("soundcloud:tracks:5".to_string()).unchecked();
+    assert!(client.get_artist_station(wrong_kind).await.is_err());
+
+    let urn: StationUrn = "soundcloud:artist-stations:7".parse().unwrap();
+    let queue: Vec<Track> = client
+        .stream_station_tracks(&urn, None)
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<TrackId> = queue.iter().filter_map(|t| t.id).collect();
+    assert_eq!(ids, vec![TrackId(10), TrackId(11), TrackId(12)]);
+
+    let requests = server.requests();
+    assert!(
+        requests[2]
+            .target
+            .starts_with("/stations/soundcloud:artist-stations:7/tracks?")
+    );
+}
+
+#[test]
+fn test_station_urn_requires_station_kind() {
+    let urn: StationUrn = "soundcloud:track-stations:5".parse().unwrap();
+    assert_eq!(urn.urn().kind, "track-stations");
+    assert_eq!(urn.to_string(), "soundcloud:track-stations:5");
+    assert!("soundcloud:tracks:5".parse::<StationUrn>().is_err());
+    assert!(serde_json::from_str::<StationUrn>(r#""soundcloud:users:5""#).is_err());
+}
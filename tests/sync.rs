@@ -0,0 +1,204 @@
+mod common;
+
+use common::{StandInServer, catalog, temp_dir};
+use serde_json::json;
+use soundcloud_rs::{
+    MirrorPlaylist, MirrorSource, MirrorTrack, PlaylistId, SyncManifest, TrackId, UserId,
+};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_manifest_roundtrip() {
+    let dir = temp_dir("manifest");
+
+    let empty = SyncManifest::load(&dir).expect("Failed to load missing manifest");
+    assert!(empty.tracks.is_empty(), "Missing manifest should load as empty");
+
+    let manifest = SyncManifest {
+        synced_at: Some(1),
+        tracks: vec![MirrorTrack {
+            id: TrackId(1),
+            urn: Some("soundcloud:tracks:1".to_string()),
+            title: Some("Song".to_string()),
+            permalink_url: None,
+            last_modified: Some("2024-01-01T00:00:00Z".to_string()),
+            sources: vec![MirrorSource::Upload, MirrorSource::Playlist],
+            path: Some("tracks/1 - Song.mp3".into()),
+            downloaded_at: Some(1),
+            removed: true,
+            removed_at: Some(2),
+        }],
+        playlists: vec![MirrorPlaylist {
+            id: PlaylistId(7),
+            urn: None,
+            title: Some("Mix".to_string()),
+            permalink_url: None,
+            last_modified: None,
+            track_ids: vec![TrackId(3), TrackId(1), TrackId(2)],
+            removed: false,
+            removed_at: None,
+        }],
+        ..Default::default()
+    };
+    manifest.save(&dir).expect("Failed to save manifest");
+
+    let loaded = SyncManifest::load(&dir).expect("Failed to load manifest");
+    let track = loaded
+        .track(TrackId(1))
+        .expect("Track should be in manifest");
+    assert!(track.removed, "Removed tracks should stay recorded");
+    assert_eq!(track.sources, vec![MirrorSource::Upload, MirrorSource::Playlist]);
+    assert_eq!(
+        loaded.playlist(PlaylistId(7)).map(|p| p.track_ids.clone()),
+        Some(vec![TrackId(3), TrackId(1), TrackId(2)])
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+/// Uploads listed by the stand-in server as `(id, last_modified)`, and ids whose track
+/// lookup fails
+#[derive(Default)]
+struct Profile {
+    uploads: Vec<(i64, &'static str)>,
+    failing: Vec<i64>,
+}
+
+async fn start_profile(profile: Arc<Mutex<Profile>>) -> StandInServer {
+    StandInServer::start_linked(move |url, req| {
+        let profile = profile.lock().unwrap();
+        let uploads: Vec<_> = profile
+            .uploads
+            .iter()
+            .map(|(id, modified)| {
+                json!({"id": id, "title": format!("Song {id}"), "last_modified": modified})
+            })
+            .collect();
+        match req.path() {
+            "/users/1" => (200, r#"{"id":1,"username":"artist"}"#.to_string()),
+            "/users/1/tracks" => (200, json!({"collection": uploads}).to_string()),
+            "/stream/users/1/reposts" | "/users/1/playlists" => {
+                (200, r#"{"collection":[]}"#.to_string())
+            }
+            path => {
+                let failing = path
+                    .strip_prefix("/tracks/")
+                    .and_then(|id| id.parse().ok())
+                    .is_some_and(|id| profile.failing.contains(&id));
+                if failing {
+                    return (404, "{}".to_string());
+                }
+                catalog(url, req, "sq").unwrap_or((404, "{}".to_string()))
+            }
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_sync_new_changed_removed_and_failed() {
+    let dir = temp_dir("sync-runs");
+    let directory = dir.to_str().unwrap();
+    let profile = Arc::new(Mutex::new(Profile {
+        uploads: vec![(1, "2024-01-01"), (2, "2024-01-01"), (3, "2024-01-01")],
+        failing: vec![3],
+    }));
+    let server = start_profile(profile.clone()).await;
+    let client = server.client().await;
+
+    let report = client.sync_user(UserId(1), directory, None).await.unwrap();
+    assert_eq!(report.downloaded, vec![TrackId(1), TrackId(2)]);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, TrackId(3));
+    assert!(dir.join("tracks/1 - Song 1.mp3").exists());
+
+    *profile.lock().unwrap() = Profile {
+        uploads: vec![(1, "2024-02-01"), (3, "2024-01-01")],
+        failing: Vec::new(),
+    };
+    let report = client.sync_user(UserId(1), directory, None).await.unwrap();
+    assert_eq!(report.updated, vec![TrackId(1)]);
+    assert_eq!(report.downloaded, vec![TrackId(3)]);
+    assert_eq!(report.removed, vec![TrackId(2)]);
+    assert!(report.failed.is_empty());
+
+    let report = client.sync_user(UserId(1), directory, None).await.unwrap();
+    assert_eq!(report.unchanged, vec![TrackId(1), TrackId(3)]);
+    assert!(report.removed.is_empty(), "Removals are only reported once");
+
+    let manifest = SyncManifest::load(&dir).unwrap();
+    assert!(manifest.track(TrackId(2)).unwrap().removed);
+    assert_eq!(
+        manifest.track(TrackId(3)).unwrap().path,
+        Some("tracks/3 - Song 3.mp3".into())
+    );
+}
+
+#[tokio::test]
+async fn test_sync_retries_failed_updates() {
+    let dir = temp_dir("sync-retry");
+    let directory = dir.to_str().unwrap();
+    let profile = Arc::new(Mutex::new(Profile {
+        uploads: vec![(1, "2024-01-01")],
+        failing: Vec::new(),
+    }));
+    let server = start_profile(profile.clone()).await;
+    let client = server.client().await;
+
+    let report = client.sync_user(UserId(1), directory, None).await.unwrap();
+    assert_eq!(report.downloaded, vec![TrackId(1)]);
+
+    *profile.lock().unwrap() = Profile {
+        uploads: vec![(1, "2024-02-01")],
+        failing: vec![1],
+    };
+    let report = client.sync_user(UserId(1), directory, None).await.unwrap();
+    assert_eq!(report.failed.len(), 1);
+    let manifest = SyncManifest::load(&dir).unwrap();
+    assert_eq!(
+        manifest.track(TrackId(1)).unwrap().last_modified.as_deref(),
+        Some("2024-01-01")
+    );
+
+    profile.lock().unwrap().failing.clear();
+    let report = client.sync_user(UserId(1), directory, None).await.unwrap();
+    assert_eq!(report.updated, vec![TrackId(1)]);
+    let manifest = SyncManifest::load(&dir).unwrap();
+    assert_eq!(
+        manifest.track(TrackId(1)).unwrap().last_modified.as_deref(),
+        Some("2024-02-01")
+    );
+}
+
+#[tokio::test]
+async fn test_sync_reports_archived_tracks_as_skipped() {
+    let dir = temp_dir("sync-skipped");
+    let profile = Arc::new(Mutex::new(Profile {
+        uploads: vec![(5, "2024-01-01")],
+        failing: Vec::new(),
+    }));
+    let server = start_profile(profile).await;
+    let client = server
+        .builder()
+        .with_download_archive(dir.join("archive.json"))
+        .build()
+        .await
+        .unwrap();
+
+    let downloads = dir.join("downloads");
+    client
+        .download_track(TrackId(5), None, downloads.to_str(), None)
+        .await
+        .unwrap();
+
+    let mirror = dir.join("mirror");
+    let report = client
+        .sync_user(UserId(1), mirror.to_str().unwrap(), None)
+        .await
+        .unwrap();
+    assert_eq!(report.skipped, vec![TrackId(5)]);
+    assert!(report.downloaded.is_empty() && report.updated.is_empty());
+
+    let manifest = SyncManifest::load(&mirror).unwrap();
+    assert_eq!(manifest.track(TrackId(5)).unwrap().path, None);
+}
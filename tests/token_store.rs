@@ -0,0 +1,123 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{FileTokenStore, MemoryTokenStore, OAuthConfig, Token, TokenStore, TrackId};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+fn token(access_token: &str) -> Token {
+    Token {
+        access_token: access_token.to_string(),
+        refresh_token: Some("refresh".to_string()),
+        token_type: Some("bearer".to_string()),
+        scope: None,
+        expires_at: Some(4_000_000_000),
+    }
+}
+
+fn temp_store(name: &str) -> FileTokenStore {
+    let dir = std::env::temp_dir().join(format!("soundcloud-rs-{}-{name}", std::process::id()));
+    FileTokenStore::new(dir.join("token.json"))
+}
+
+#[test]
+fn test_memory_store() {
+    let store = MemoryTokenStore::new();
+    assert_eq!(store.load().unwrap(), None);
+    store.save(&token("abc")).unwrap();
+    assert_eq!(store.load().unwrap(), Some(token("abc")));
+    store.clear().unwrap();
+    assert_eq!(store.load().unwrap(), None);
+}
+
+#[test]
+fn test_file_store_roundtrip_with_private_permissions() {
+    let store = temp_store("file-store");
+    assert_eq!(store.load().unwrap(), None, "Missing file should load as no token");
+
+    store.save(&token("abc")).unwrap();
+    assert_eq!(FileTokenStore::new(&store.path).load().unwrap(), Some(token("abc")));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&store.path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600, "Token file should only be readable by the owner");
+    }
+
+    store.clear().unwrap();
+    assert_eq!(store.load().unwrap(), None);
+    std::fs::remove_dir_all(store.path.parent().unwrap()).ok();
+}
+
+#[test]
+fn test_file_store_lock_is_exclusive() {
+    let store = Arc::new(temp_store("file-lock"));
+    let lock = store.lock().unwrap();
+
+    let acquired = Arc::new(AtomicBool::new(false));
+    let handle = {
+        let store = FileTokenStore::new(&store.path);
+        let acquired = acquired.clone();
+        std::thread::spawn(move || {
+            let _lock = store.lock().unwrap();
+            acquired.store(true, Ordering::SeqCst);
+        })
+    };
+
+    std::thread::sleep(Duration::from_millis(200));
+    assert!(!acquired.load(Ordering::SeqCst), "Second lock should wait for the first");
+    drop(lock);
+    handle.join().unwrap();
+    assert!(acquired.load(Ordering::SeqCst));
+    std::fs::remove_dir_all(store.path.parent().unwrap()).ok();
+}
+
+#[test]
+fn test_file_store_leaves_no_temp_files() {
+    let store = temp_store("file-tmp");
+    store.save(&token("abc")).unwrap();
+    store.save(&token("def")).unwrap();
+
+    let dir = store.path.parent().unwrap();
+    let names: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(names, vec!["token.json"]);
+    std::fs::remove_dir_all(dir).ok();
+}
+
+#[tokio::test]
+async fn test_client_writes_refreshed_token_to_store() {
+    let server = StandInServer::start(|req| match req.path() {
+        "/oauth/token" => (
+            200,
+            r#"{"access_token":"fresh","refresh_token":"rotated","expires_in":3600}"#.to_string(),
+        ),
+        _ => (200, r#"{"id":1}"#.to_string()),
+    })
+    .await;
+    let store = temp_store("client-refresh");
+    let mut expired = token("stale");
+    expired.expires_at = Some(0);
+    store.save(&expired).unwrap();
+
+    let mut config = OAuthConfig::authorization_code("app", None, "http://127.0.0.1:1/callback");
+    config.token_url = format!("{}/oauth/token", server.url);
+    let client = server
+        .builder()
+        .with_oauth(config)
+        .with_token_store(store.clone())
+        .build()
+        .await
+        .unwrap();
+    client.get_track(TrackId(1)).await.unwrap();
+
+    assert!(server.requests()[0].body.contains("refresh_token=refresh"));
+    let stored = store.load().unwrap().expect("Refreshed token should be stored");
+    assert_eq!(stored.access_token, "fresh");
+    assert_eq!(stored.refresh_token.as_deref(), Some("rotated"));
+    std::fs::remove_dir_all(store.path.parent().unwrap()).ok();
+}
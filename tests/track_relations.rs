@@ -0,0 +1,88 @@
+mod common;
+
+use common::StandInServer;
+use futures::TryStreamExt;
+use soundcloud_rs::{Paging, PlaylistId, TrackId, UserId, response::User};
+
+#[tokio::test]
+async fn test_track_relation_paths() {
+    let server = StandInServer::start(|_| (200, r#"{"collection":[{"id":1}]}"#.to_string())).await;
+    let client = server.client().await;
+    let id = TrackId(5);
+    let paging = Paging {
+        limit: Some(10),
+        ..Default::default()
+    };
+
+    let likers = client.get_track_likers(&id, Some(&paging)).await.unwrap();
+    assert_eq!(likers.collection[0].id, Some(UserId(1)));
+    client.get_track_reposters(&id, None).await.unwrap();
+    let playlists = client.get_track_playlists(&id, None).await.unwrap();
+    assert_eq!(playlists.collection[0].id, Some(PlaylistId(1)));
+    client.get_track_albums(&id, None).await.unwrap();
+
+    assert_eq!(
+        server.paths(),
+        vec![
+            "/tracks/5/likers",
+            "/tracks/5/reposters",
+            "/tracks/5/playlists_without_albums",
+            "/tracks/5/albums",
+        ]
+    );
+    assert!(server.requests()[0].target.contains("limit=10"));
+}
+
+#[tokio::test]
+async fn test_stream_track_likers_follows_pages() {
+    let server = StandInServer::start_linked(|url, req| {
+        if req.target.contains("cursor=2") {
+            (200, r#"{"collection":[{"id":3}]}"#.to_string())
+        } else {
+            let body = format!(
+                r#"{{"collection":[{{"id":1}},{{"id":2}}],"next_href":"{}/tracks/5/likers?cursor=2"}}"#,
+                url
+            );
+            (200, body)
+        }
+    })
+    .await;
+    let client = server.client().await;
+
+    let likers: Vec<User> = client
+        .stream_track_likers(TrackId(5), None)
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<UserId> = likers.iter().filter_map(|u| u.id).collect();
+    assert_eq!(ids, vec![UserId(1), UserId(2), UserId(3)]);
+    assert!(
+        server.requests()[0]
+            .target
+            .contains("linked_partitioning=true")
+    );
+}
+
+#[tokio::test]
+async fn test_collect_pages_stops_at_empty_page() {
+    let server = StandInServer::start_linked(|url, req| {
+        let (items, cursor) = if req.target.contains("cursor=3") {
+            ("", 4)
+        } else if req.target.contains("cursor=2") {
+            (r#"{"id":3}"#, 3)
+        } else {
+            (r#"{"id":1},{"id":2}"#, 2)
+        };
+        let next_href = format!("{url}/tracks/5/likers?cursor={cursor}");
+        let body = format!(r#"{{"collection":[{items}],"next_href":"{next_href}"}}"#);
+        (200, body)
+    })
+    .await;
+    let client = server.client().await;
+
+    let first = client.get_track_likers(TrackId(5), None).await.unwrap();
+    let likers = client.collect_pages(first).await.unwrap();
+    let ids: Vec<UserId> = likers.iter().filter_map(|u| u.id).collect();
+    assert_eq!(ids, vec![UserId(1), UserId(2), UserId(3)]);
+    assert_eq!(server.requests().len(), 3);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::StandInServer;
+use serde_json::{Value, json};
+use soundcloud_rs::{TrackId, TrackParams, UploadProgress};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_upload_track_streams_file_and_waits_for_processing() {
+    let server = StandInServer::start(|req| match req.method.as_str() {
+        "POST" => (201, r#"{"id":77,"state":"processing"}"#.to_string()),
+        _ => (
+            200,
+            r#"{"id":77,"state":"finished","title":"Master"}"#.to_string(),
+        ),
+    })
+    .await;
+    let client = server
+        .builder()
+        .with_public_api_url(server.url.clone())
+        .build()
+        .await
+        .unwrap();
+
+    let audio =
+        std::env::temp_dir().join(format!("soundcloud-rs-{}-master.wav", std::process::id()));
+    let data = vec![7u8; 150 * 1024];
+    std::fs::write(&audio, &data).unwrap();
+
+    let params = TrackParams {
+        title: Some("Master".to_string()),
+        isrc: Some("USABC2600001".to_string()),
+        downloadable: Some(false),
+        ..Default::default()
+    };
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = progress.clone();
+    let track = client
+        .upload_track(&audio, None, &params, move |p: UploadProgress| {
+            recorded.lock().unwrap().push(p)
+        })
+        .await
+        .expect("Upload should succeed");
+    std::fs::remove_file(&audio).unwrap();
+
+    assert_eq!(track.id, Some(TrackId(77)));
+    assert_eq!(track.state.as_deref(), Some("finished"));
+
+    let progress = progress.lock().unwrap();
+    assert!(progress.len() > 1, "Audio should be sent in several chunks");
+    let last = progress.last().unwrap();
+    assert_eq!(last.bytes_sent, data.len() as u64);
+    assert_eq!(last.fraction(), 1.0);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert!(requests[0].target.starts_with("/tracks?"));
+    let content_type = requests[0].header("content-type").unwrap();
+    assert!(content_type.starts_with("multipart/form-data"));
+    let body = &requests[0].body;
+    assert!(body.contains(r#"name="track[asset_data]"; filename="soundcloud-rs-"#));
+    assert!(body.contains("name=\"track[title]\"\r\n\r\nMaster"));
+    assert!(body.contains("name=\"track[isrc]\"\r\n\r\nUSABC2600001"));
+    assert!(body.contains("name=\"track[downloadable]\"\r\n\r\nfalse"));
+    assert!(!body.contains("track[genre]"));
+    assert_eq!(requests[1].method, "GET");
+    assert!(requests[1].target.starts_with("/tracks/77?"));
+}
+
+#[tokio::test]
+async fn test_update_track_and_failed_processing() {
+    let server = StandInServer::start(|req| match req.method.as_str() {
+        "PUT" => (200, r#"{"id":5,"genre":"Techno"}"#.to_string()),
+        _ => (200, r#"{"id":5,"state":"failed"}"#.to_string()),
+    })
+    .await;
+    let client = server
+        .builder()
+        .with_public_api_url(server.url.clone())
+        .build()
+        .await
+        .unwrap();
+
+    let params = TrackParams {
+        genre: Some("Techno".to_string()),
+        ..Default::default()
+    };
+    let track = client.update_track(TrackId(5), &params).await.unwrap();
+    assert_eq!(track.genre.as_deref(), Some("Techno"));
+    let body: Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+    assert_eq!(body, json!({ "track": { "genre": "Techno" } }));
+
+    let result = client
+        .wait_for_track_processing(
+            TrackId(5),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        )
+        .await;
+    assert!(result.is_err(), "Failed processing should be an error");
+}
+
+#[tokio::test]
+async fn test_processing_times_out() {
+    let server =
+        StandInServer::start(|_| (200, r#"{"id":5,"state":"processing"}"#.to_string())).await;
+    let client = server
+        .builder()
+        .with_public_api_url(server.url.clone())
+        .build()
+        .await
+        .unwrap();
+
+    let err = client
+        .wait_for_track_processing(
+            TrackId(5),
+            Duration::from_millis(20),
+            Duration::from_millis(100),
+        )
+        .await
+        .expect_err("Processing that never ends should time out");
+    assert!(err.to_string().contains("still processing"));
+    let polls = server.requests().len();
+    assert!((2..=6).contains(&polls), "Polled {polls} times");
+}
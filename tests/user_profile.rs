@@ -0,0 +1,71 @@
+mod common;
+
+use common::StandInServer;
+use soundcloud_rs::{PlaylistId, ResourceKind, TrackId, UserId, response::SpotlightItem};
+
+#[tokio::test]
+async fn test_user_profile_endpoints() {
+    let server = StandInServer::start(|req| {
+        let path = req.path();
+        let body = match path {
+            "/users/7/spotlight" => {
+                r#"{"collection":[{"kind":"track","id":1,"title":"Pinned"},{"kind":"playlist","id":2},{"kind":"system-playlist","id":"x"}]}"#
+            }
+            "/users/soundcloud:users:7/web-profiles" => {
+                r#"[{"id":3,"network":"instagram","url":"https://instagram.com/artist","title":"Instagram"}]"#
+            }
+            "/users/7/track_likes" => {
+                r#"{"collection":[{"kind":"like","track":{"id":4}}]}"#
+            }
+            _ => r#"{"collection":[{"id":5}]}"#,
+        };
+        (200, body.to_string())
+    })
+    .await;
+    let client = server.client().await;
+    let id = UserId(7);
+
+    let spotlight = client.get_user_spotlight(&id, None).await.unwrap();
+    assert!(
+        matches!(&spotlight.collection[0], SpotlightItem::Track(t) if t.title.as_deref() == Some("Pinned"))
+    );
+    assert!(
+        matches!(&spotlight.collection[1], SpotlightItem::Playlist(p) if p.id == Some(PlaylistId(2)))
+    );
+    assert!(matches!(&spotlight.collection[2], SpotlightItem::Other(v) if v["id"] == "x"));
+
+    let profiles = client.get_user_web_profiles(&id).await.unwrap();
+    assert_eq!(profiles[0].service.as_deref(), Some("instagram"));
+    assert_eq!(
+        profiles[0].url.as_deref(),
+        Some("https://instagram.com/artist")
+    );
+
+    let likes = client.get_user_liked_tracks(&id, None).await.unwrap();
+    assert_eq!(likes.collection[0].kind, Some(ResourceKind::Like));
+    assert_eq!(
+        likes.collection[0].track.as_ref().unwrap().id,
+        Some(TrackId(4))
+    );
+
+    client.get_user_liked_playlists(&id, None).await.unwrap();
+    let albums = client.get_user_albums(&id, None).await.unwrap();
+    assert_eq!(albums.collection[0].id, Some(PlaylistId(5)));
+    let top = client.get_user_top_tracks(&id, None).await.unwrap();
+    assert_eq!(top.collection[0].id, Some(TrackId(5)));
+
+    let paths: Vec<String> = server
+        .requests()
+        .iter()
+        .skip(3)
+        .map(|r| r.path().to_string())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/users/7/playlist_likes",
+            "/users/7/albums",
+            "/users/7/toptracks"
+        ]
+    );
+}
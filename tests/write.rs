@@ -0,0 +1,126 @@
+mod common;
+
+use common::StandInServer;
+use serde_json::{Value, json};
+use soundcloud_rs::{PlaylistId, RequestBody, TrackId, UserId};
+
+async fn client_for(server: &StandInServer) -> soundcloud_rs::Client {
+    server
+        .builder()
+        .build()
+        .await
+        .expect("Failed to build client")
+}
+
+#[tokio::test]
+async fn test_like_and_unlike_track() {
+    let server = StandInServer::start(|_| (200, String::new())).await;
+    let client = client_for(&server).await;
+
+    client
+        .like_track(TrackId(7))
+        .await
+        .expect("Like should succeed");
+    client
+        .unlike_track(TrackId(7))
+        .await
+        .expect("Unlike should succeed");
+
+    let writes = server.requests();
+    assert_eq!(writes.len(), 2, "Likes should not look up /me first");
+    assert_eq!(writes[0].method, "PUT");
+    assert!(writes[0].target.starts_with("/me/track_likes/7?"));
+    assert_eq!(writes[1].method, "DELETE");
+    assert!(writes[1].target.starts_with("/me/track_likes/7?"));
+    assert!(writes[0].target.contains("client_id=test"));
+}
+
+#[tokio::test]
+async fn test_repost_follow_and_playlist_like_paths() {
+    let server = StandInServer::start(|_| (200, "{}".to_string())).await;
+    let client = client_for(&server).await;
+
+    client.repost_track(TrackId(1)).await.unwrap();
+    client.unrepost_track(TrackId(1)).await.unwrap();
+    client.like_playlist(PlaylistId(2)).await.unwrap();
+    client.unlike_playlist(PlaylistId(2)).await.unwrap();
+    client.repost_playlist(PlaylistId(2)).await.unwrap();
+    client.unrepost_playlist(PlaylistId(2)).await.unwrap();
+    client.follow_user(UserId(3)).await.unwrap();
+    client.unfollow_user(UserId(3)).await.unwrap();
+
+    let writes: Vec<(String, String)> = server
+        .requests()
+        .into_iter()
+        .map(|r| (r.method.clone(), r.path().to_string()))
+        .collect();
+    let expected = [
+        ("PUT", "/me/track_reposts/1"),
+        ("DELETE", "/me/track_reposts/1"),
+        ("PUT", "/me/playlist_likes/2"),
+        ("DELETE", "/me/playlist_likes/2"),
+        ("PUT", "/me/playlist_reposts/2"),
+        ("DELETE", "/me/playlist_reposts/2"),
+        ("POST", "/me/followings/3"),
+        ("DELETE", "/me/followings/3"),
+    ];
+    let expected: Vec<(String, String)> = expected
+        .iter()
+        .map(|(m, p)| (m.to_string(), p.to_string()))
+        .collect();
+    assert_eq!(writes, expected);
+}
+
+#[tokio::test]
+async fn test_post_track_comment_sends_json_body() {
+    let server = StandInServer::start(|_| {
+        (
+            201,
+            r#"{"id":9,"body":"nice drop","timestamp":61000,"track_id":5}"#.to_string(),
+        )
+    })
+    .await;
+    let client = client_for(&server).await;
+
+    let comment = client
+        .post_track_comment(TrackId(5), "nice drop", Some(61000))
+        .await
+        .expect("Comment should be posted");
+    assert_eq!(comment.id, Some(9));
+    assert_eq!(comment.timestamp, Some(61000));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert!(requests[0].target.starts_with("/tracks/5/comments?"));
+    let body: Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        json!({ "comment": { "body": "nice drop", "timestamp": 61000 } })
+    );
+}
+
+#[tokio::test]
+async fn test_send_form_body_and_report_errors() {
+    let server = StandInServer::start(|req| match req.method.as_str() {
+        "PUT" => (403, r#"{"error":"forbidden"}"#.to_string()),
+        _ => (200, r#"{"ok":true}"#.to_string()),
+    })
+    .await;
+    let client = client_for(&server).await;
+    let form = RequestBody::Form(vec![("title".to_string(), "x".to_string())]);
+    let resp: Value = client.post("anything", &form).await.unwrap();
+    assert_eq!(resp, json!({ "ok": true }));
+
+    let err = client
+        .put::<Value>("anything", &RequestBody::Empty)
+        .await
+        .expect_err("403 should be an error");
+    assert!(err.to_string().contains("403"));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].body, "title=x");
+    assert_eq!(
+        requests[0].header("content-type"),
+        Some("application/x-www-form-urlencoded")
+    );
+}